@@ -0,0 +1,1078 @@
+extern crate alloc;
+
+use alloc::{
+	borrow::Cow,
+	collections::{BTreeSet, VecDeque},
+	vec::{Drain, Vec},
+};
+use core::ops::Range;
+
+use crate::{CollectionCursor, IndexableCollection};
+
+impl<T> CollectionCursor<Vec<T>> {
+	/// Creates a new `CollectionCursor` from the `Ok` items of `iter`, with the cursor at index
+	/// `0`.
+	///
+	/// # Errors
+	/// If `iter` yields an `Err`, no further items are taken from it, and that error is returned.
+	pub fn try_from_iter<E, I: IntoIterator<Item = Result<T, E>>>(iter: I) -> Result<Self, E> {
+		iter.into_iter()
+			.collect::<Result<Vec<_>, _>>()
+			.map(Self::new)
+	}
+
+	/// Removes all but the first of consecutive items at or after the cursor that resolve to the
+	/// same key. Items before the cursor are left untouched, and the cursor is not moved.
+	///
+	/// This is equivalent to calling [`Vec::dedup_by_key`] on only the items from
+	/// `self.position()` to the end of the collection.
+	pub fn dedup_by_key_from_cursor<K: PartialEq, F: FnMut(&mut T) -> K>(&mut self, key: F) {
+		let mut remaining = self.inner.split_off(self.pos.min(self.inner.len()));
+		remaining.dedup_by_key(key);
+		self.inner.append(&mut remaining);
+	}
+
+	/// Removes `remove` items starting at the cursor, and inserts the items of `replacement` in
+	/// their place. The cursor is left at the start of the inserted items.
+	///
+	/// The number of items in `replacement` does not need to match `remove` - the collection will
+	/// grow or shrink as needed.
+	///
+	/// # Panics
+	/// Panics if `self.position() + remove > self.get_ref().len()`.
+	pub fn replace_n_at_cursor(&mut self, remove: usize, replacement: impl IntoIterator<Item = T>) {
+		self.inner.splice(self.pos..self.pos + remove, replacement);
+	}
+
+	/// Clones the items within `src`, and inserts the clones at the cursor. `src` may include or
+	/// overlap the cursor, as the items are cloned before any are inserted.
+	///
+	/// The cursor is moved forward by the number of items inserted, so that it keeps pointing at
+	/// the same item.
+	///
+	/// # Panics
+	/// Panics if `src` is decreasing or ends past the end of the collection, or if
+	/// `self.position() > self.get_ref().len()`.
+	pub fn duplicate_region_at_cursor(&mut self, src: Range<usize>)
+	where
+		T: Clone,
+	{
+		let items = self.inner[src].to_vec();
+		let insert_at = self.pos;
+
+		self.pos += items.len();
+		self.inner.splice(insert_at..insert_at, items);
+	}
+
+	/// Inserts `item` at the index that keeps the collection sorted, and returns that index. If
+	/// equal items already exist, `item` is inserted after them.
+	///
+	/// If `item` is inserted at or before the cursor, the cursor is moved forward by one, so that
+	/// it keeps pointing at the same item.
+	///
+	/// The collection must already be sorted. If it isn't, the index `item` is inserted at is
+	/// unspecified and meaningless, but the cursor will still be adjusted as described above.
+	pub fn sorted_insert(&mut self, item: T) -> usize
+	where
+		T: Ord,
+	{
+		let index = self.inner.partition_point(|existing| existing <= &item);
+		self.inner.insert(index, item);
+
+		if index <= self.pos {
+			self.pos += 1;
+		}
+
+		index
+	}
+
+	/// Inserts all of `items` at the indices that keep the collection sorted. As with
+	/// [`Self::sorted_insert()`], items equal to existing items are inserted after them.
+	///
+	/// The cursor is moved forward by the number of items inserted before the item it points at,
+	/// so that it keeps pointing at the same item. If the cursor is at the end of the collection,
+	/// it stays at the end.
+	///
+	/// This appends the items and sorts the collection once, rather than inserting each item
+	/// individually. The collection must already be sorted. If it isn't, the resulting order of
+	/// the items, and the item the cursor points at afterwards, are unspecified and meaningless.
+	pub fn insert_sorted_batch(&mut self, items: impl IntoIterator<Item = T>)
+	where
+		T: Ord,
+	{
+		let old_len = self.inner.len();
+		self.inner.extend(items);
+		let (existing, inserted) = self.inner.split_at(old_len);
+
+		self.pos += match existing.get(self.pos) {
+			Some(item_at_cursor) => inserted
+				.iter()
+				.filter(|item| *item < item_at_cursor)
+				.count(),
+			None => inserted.len(),
+		};
+		// A stable sort keeps existing items before inserted items that are equal to them
+		self.inner.sort();
+	}
+
+	/// Removes the consecutive items starting at the cursor for which `pred` returns `true`, and
+	/// returns them in order.
+	///
+	/// The cursor is not moved, and afterwards points at the first item for which `pred` returned
+	/// `false`, or at the end of the collection if there is no such item.
+	pub fn drain_while_from_cursor<F: FnMut(&T) -> bool>(&mut self, pred: F) -> Vec<T> {
+		let start = self.pos.min(self.inner.len());
+		let run_len = self.run_len_from_cursor(pred);
+
+		self.inner.drain(start..start + run_len).collect()
+	}
+
+	/// Removes the consecutive items starting at the cursor for which `pred` returns `true`, and
+	/// moves them in order into `sink`. Returns the number of items moved.
+	///
+	/// This is equivalent to [`Self::drain_while_from_cursor()`], except that the items are passed
+	/// to `sink` directly, rather than being collected into a new `Vec` first. The cursor is not
+	/// moved, and afterwards points at the first item for which `pred` returned `false`, or at the
+	/// end of the collection if there is no such item.
+	pub fn drain_while_into<F: FnMut(&T) -> bool>(
+		&mut self,
+		pred: F,
+		sink: &mut impl Extend<T>,
+	) -> usize {
+		let start = self.pos.min(self.inner.len());
+		let run_len = self.run_len_from_cursor(pred);
+
+		sink.extend(self.inner.drain(start..start + run_len));
+		run_len
+	}
+
+	/// Reorders the items at or after the cursor, so that all items for which `pred` returns
+	/// `true` come before all items for which it returns `false`. The relative order of the items
+	/// within each group is preserved.
+	///
+	/// Returns the index of the first item for which `pred` returned `false`, or the length of
+	/// the collection if there is no such item. Items before the cursor are left untouched, and
+	/// the cursor is not moved.
+	pub fn stable_partition_from_cursor<F: FnMut(&T) -> bool>(&mut self, pred: F) -> usize {
+		let start = self.pos.min(self.inner.len());
+		let (matching, rest): (Vec<T>, Vec<T>) =
+			self.inner.split_off(start).into_iter().partition(pred);
+
+		let partition_index = start + matching.len();
+		self.inner.extend(matching);
+		self.inner.extend(rest);
+
+		partition_index
+	}
+
+	/// Removes every item at or after the cursor, and returns them as an iterator. Afterwards, the
+	/// collection only contains the items before the cursor, and the cursor is at its end.
+	///
+	/// This is equivalent to calling [`Vec::drain`] with `self.position()..`, and so the items are
+	/// removed even if the iterator is dropped before being fully consumed.
+	///
+	/// This is only available for `Vec`s. For other collections, see
+	/// [`Self::drain_items_from_cursor()`].
+	pub fn drain_from_cursor(&mut self) -> Drain<'_, T> {
+		self.pos = self.pos.min(self.inner.len());
+		self.inner.drain(self.pos..)
+	}
+
+	/// Removes every item from the collection, regardless of the cursor's position, and returns
+	/// them as an iterator. The cursor is returned to the index `0`.
+	///
+	/// As with [`Vec::drain`], the items are removed even if the iterator is dropped before being
+	/// fully consumed. However, if the iterator is leaked (for example, through
+	/// [`core::mem::forget`]), the collection may lose and leak items arbitrarily.
+	pub fn drain_all(&mut self) -> Drain<'_, T> {
+		self.pos = 0;
+		self.inner.drain(..)
+	}
+
+	/// Removes the items within `range`, and returns them in order. Returns `None` if `range` is
+	/// decreasing or ends past the end of the collection, in which case nothing is removed.
+	///
+	/// The cursor is adjusted as follows:
+	///
+	/// * If the cursor was at or after the end of `range`, it is moved back by the number of items
+	///   removed, so that it keeps pointing at the same item.
+	/// * If the cursor was within `range`, it is moved to `range.start`, which is now the first
+	///   item after the removed items.
+	/// * Otherwise, the cursor is not moved.
+	pub fn remove_range(&mut self, range: Range<usize>) -> Option<Vec<T>> {
+		if range.start > range.end || range.end > self.inner.len() {
+			return None;
+		}
+
+		if self.pos >= range.end {
+			self.pos -= range.len();
+		} else if self.pos >= range.start {
+			self.pos = range.start;
+		}
+
+		Some(self.inner.drain(range).collect())
+	}
+
+	/// Inserts all items of `other`'s collection at the start of this collection, in order,
+	/// regardless of `other`'s position. The cursor is moved forward by the number of items
+	/// inserted, so that it keeps pointing at the same item.
+	pub fn prepend_from(&mut self, other: CollectionCursor<Vec<T>>) {
+		let items = other.into_inner();
+		self.pos += items.len();
+		self.inner.splice(0..0, items);
+	}
+
+	/// Replaces the consecutive items starting at the cursor for which `pred` returns `true` with
+	/// a single `replacement` item, and returns the number of items that were replaced.
+	///
+	/// If `pred` returns `false` for the item at the cursor, nothing is replaced, `replacement` is
+	/// dropped, and `0` is returned. The cursor is not moved, and so points at `replacement` if
+	/// any items were replaced.
+	pub fn collapse_run_at_cursor<F: FnMut(&T) -> bool>(
+		&mut self,
+		pred: F,
+		replacement: T,
+	) -> usize {
+		let run_len = self.run_len_from_cursor(pred);
+		if run_len > 0 {
+			self.inner
+				.splice(self.pos..self.pos + run_len, [replacement]);
+		}
+
+		run_len
+	}
+
+	/// Converts this cursor into one over a `VecDeque` containing the same items. The position of
+	/// the cursor, any checkpoints, and any saved positions are carried over.
+	pub fn into_vec_deque_cursor(self) -> CollectionCursor<VecDeque<T>> {
+		CollectionCursor {
+			inner: VecDeque::from(self.inner),
+			pos: self.pos,
+			checkpoints: self.checkpoints,
+			position_stack: self.position_stack,
+		}
+	}
+
+	/// Returns the number of consecutive items starting at the cursor for which `pred` returns
+	/// `true`.
+	fn run_len_from_cursor<F: FnMut(&T) -> bool>(&self, mut pred: F) -> usize {
+		self.inner
+			.get(self.pos..)
+			.unwrap_or_default()
+			.iter()
+			.take_while(|item| pred(item))
+			.count()
+	}
+}
+
+impl<T> CollectionCursor<VecDeque<T>> {
+	/// Rearranges the items of the inner `VecDeque` so that they are stored contiguously, and
+	/// returns them as a mutable slice, in order.
+	///
+	/// This calls [`VecDeque::make_contiguous`], and so may move items around within the deque's
+	/// buffer. However, the order of the items (and so the item the cursor points at) does not
+	/// change.
+	///
+	/// The returned slice is the only contiguous view of the items. `VecDeque` does not implement
+	/// [`ContiguousCollection`](crate::ContiguousCollection), as its items aren't always stored
+	/// contiguously, so the cursor's slice-based methods (such as [`Self::remaining_slice()`])
+	/// remain unavailable, even after calling this. Until the deque is next modified,
+	/// [`Self::remaining_as_cow()`] will however borrow the items rather than clone them.
+	pub fn make_contiguous(&mut self) -> &mut [T] {
+		self.inner.make_contiguous()
+	}
+}
+
+impl<Tape: IndexableCollection> CollectionCursor<Tape> {
+	/// Returns clones of the items before the cursor, and of the items from the cursor to the end
+	/// of the collection, as two separate `Vec`s. Neither the cursor nor the collection is
+	/// modified.
+	pub fn clone_halves(&self) -> (Vec<Tape::Item>, Vec<Tape::Item>)
+	where
+		Tape::Item: Clone,
+	{
+		let split_index = self.pos.min(self.inner.len());
+
+		let before = (0..split_index)
+			.filter_map(|index| self.inner.get_item(index))
+			.cloned()
+			.collect();
+		let after = self.iter_from_cursor().cloned().collect();
+
+		(before, after)
+	}
+
+	/// Returns the items from the cursor to the end of the collection as a slice.
+	///
+	/// If the collection's items are currently stored contiguously (as reported by
+	/// [`IndexableCollection::as_contiguous_slice()`]), the slice is borrowed from the
+	/// collection. Otherwise, the items are cloned into a new `Vec`.
+	pub fn remaining_as_cow(&self) -> Cow<'_, [Tape::Item]>
+	where
+		Tape::Item: Clone,
+	{
+		match self.inner.as_contiguous_slice() {
+			Some(slice) => Cow::Borrowed(slice.get(self.pos..).unwrap_or_default()),
+			None => Cow::Owned(self.iter_from_cursor().cloned().collect()),
+		}
+	}
+
+	/// Calls `f` on each item from the cursor to the end of the collection, and collects the
+	/// results into a `Vec`. The cursor is not moved.
+	///
+	/// # Errors
+	/// If `f` returns an error, no further items are mapped, and that error is returned.
+	pub fn try_map_remaining<B, E, F: FnMut(&Tape::Item) -> Result<B, E>>(
+		&self,
+		f: F,
+	) -> Result<Vec<B>, E> {
+		self.iter_from_cursor().map(f).collect()
+	}
+
+	/// Clones the next `n` items, starting at the cursor, into a `Vec`, and moves the cursor past
+	/// them. If fewer than `n` items remain, returns `None` without moving the cursor.
+	///
+	/// This is similar to `std::io::Read::read_exact()`, in that either all `n` items are read, or
+	/// none are.
+	pub fn consume_exact(&mut self, n: usize) -> Option<Vec<Tape::Item>>
+	where
+		Tape::Item: Clone,
+	{
+		let end = self.pos.checked_add(n)?;
+		if end > self.inner.len() {
+			return None;
+		}
+
+		let items = self.iter_from_cursor().take(n).cloned().collect::<Vec<_>>();
+		if items.len() != n {
+			return None;
+		}
+
+		self.pos = end;
+		Some(items)
+	}
+
+	/// Returns the number of distinct items from the cursor to the end of the collection. Neither
+	/// the cursor nor the collection is modified.
+	pub fn count_distinct_from_cursor(&self) -> usize
+	where
+		Tape::Item: Ord,
+	{
+		self.iter_from_cursor().collect::<BTreeSet<_>>().len()
+	}
+
+	/// Records the current position of the cursor under `name`, replacing any position previously
+	/// recorded under the same name.
+	pub fn set_checkpoint(&mut self, name: &'static str) {
+		self.checkpoints.insert(name, self.pos);
+	}
+
+	/// Moves the cursor to the position recorded under `name` by [`Self::set_checkpoint()`].
+	/// Returns `true` if such a position exists, or `false` otherwise.
+	///
+	/// If the collection has shrunk since the position was recorded, the cursor is clamped to
+	/// `self.get_ref().len()`.
+	pub fn goto_checkpoint(&mut self, name: &str) -> bool {
+		let Some(&pos) = self.checkpoints.get(name) else {
+			return false;
+		};
+
+		self.pos = pos.min(self.inner.len());
+		true
+	}
+
+	/// Saves the current position of the cursor onto a stack, so that it can later be returned to
+	/// with [`Self::pop_position()`].
+	///
+	/// Unlike [`Self::set_checkpoint()`], positions don't need a name, and are returned to in the
+	/// reverse order they were saved in. This makes them suited to nested backtracking.
+	pub fn push_position(&mut self) {
+		self.position_stack.push(self.pos);
+	}
+
+	/// Moves the cursor to the position most recently saved by [`Self::push_position()`], removing
+	/// it from the stack. Returns the new position of the cursor, or `None` if no positions are
+	/// saved.
+	///
+	/// If the collection has shrunk since the position was saved, the cursor is clamped to
+	/// `self.get_ref().len()`.
+	pub fn pop_position(&mut self) -> Option<usize> {
+		let pos = self.position_stack.pop()?;
+
+		self.pos = pos.min(self.inner.len());
+		Some(self.pos)
+	}
+
+	/// Returns the positions saved by [`Self::push_position()`], from the oldest to the most
+	/// recently saved.
+	///
+	/// These are the positions as they were saved, so they may be past the end of the collection
+	/// if it has shrunk since.
+	pub fn marks(&self) -> &[usize] {
+		&self.position_stack
+	}
+
+	/// Forgets all positions saved by [`Self::push_position()`]. The cursor is not moved.
+	pub fn clear_marks(&mut self) {
+		self.position_stack.clear();
+	}
+}
+
+#[cfg(test)]
+mod alloc_methods_tests {
+	use core::{
+		cmp::Ordering,
+		hash::{Hash, Hasher},
+	};
+
+	use super::*;
+	use crate::collection_cursor_tests::test_collection;
+
+	#[test]
+	fn try_from_iter() {
+		let collection = CollectionCursor::try_from_iter([Ok::<_, &str>(0), Ok(1), Ok(2)]).unwrap();
+		assert_eq!(collection.inner, Vec::from([0, 1, 2]));
+		assert_eq!(
+			collection.pos, 0,
+			"should start with the cursor at index `0`"
+		);
+
+		let mut taken = 0;
+		let result = CollectionCursor::try_from_iter(
+			[Ok(0), Err("bad item"), Ok(2)]
+				.into_iter()
+				.inspect(|_| taken += 1),
+		);
+		assert_eq!(result, Err("bad item"), "should return the first error");
+		assert_eq!(taken, 2, "should stop taking items after an error");
+	}
+
+	#[test]
+	fn dedup_by_key_from_cursor() {
+		let mut collection = CollectionCursor::new(Vec::from([1, 4, 7, 10, 2, 5, 3, 6, 9]));
+		collection.pos = 2;
+
+		collection.dedup_by_key_from_cursor(|item| *item % 3);
+		assert_eq!(
+			collection.inner,
+			Vec::from([1, 4, 7, 2, 3]),
+			"should only dedup items at or after the cursor"
+		);
+		assert_eq!(collection.pos, 2, "shouldn't move the cursor");
+	}
+
+	#[test]
+	fn replace_n_at_cursor() {
+		let mut collection = CollectionCursor::new(Vec::from([0, 1, 2, 3, 4, 5]));
+		collection.pos = 2;
+
+		collection.replace_n_at_cursor(2, [10, 11, 12]);
+		assert_eq!(
+			collection.inner,
+			Vec::from([0, 1, 10, 11, 12, 4, 5]),
+			"should grow the collection when the replacement is longer"
+		);
+		assert_eq!(
+			collection.pos, 2,
+			"should leave the cursor at the start of the replacement"
+		);
+
+		collection.replace_n_at_cursor(4, [20]);
+		assert_eq!(
+			collection.inner,
+			Vec::from([0, 1, 20, 5]),
+			"should shrink the collection when the replacement is shorter"
+		);
+		assert_eq!(
+			collection.pos, 2,
+			"should leave the cursor at the start of the replacement"
+		);
+	}
+
+	#[test]
+	fn duplicate_region_at_cursor() {
+		let mut collection = CollectionCursor::new(Vec::from([0, 1, 2, 3, 4, 5, 6]));
+		collection.pos = 5;
+
+		collection.duplicate_region_at_cursor(0..3);
+		assert_eq!(
+			collection.inner,
+			Vec::from([0, 1, 2, 3, 4, 0, 1, 2, 5, 6]),
+			"should insert clones of the region at the cursor"
+		);
+		assert_eq!(
+			collection.pos, 8,
+			"should move the cursor forward by the number of items inserted"
+		);
+		assert_eq!(collection.get_item_at_cursor(), Some(&5));
+
+		collection.duplicate_region_at_cursor(7..9);
+		assert_eq!(
+			collection.inner,
+			Vec::from([0, 1, 2, 3, 4, 0, 1, 2, 2, 5, 5, 6]),
+			"should handle a region that overlaps the cursor"
+		);
+		assert_eq!(collection.pos, 10);
+	}
+
+	#[test]
+	fn sorted_insert() {
+		let mut collection = CollectionCursor::new(Vec::from([0, 2, 4, 6, 8]));
+		collection.pos = 2;
+
+		assert_eq!(collection.sorted_insert(5), 3);
+		assert_eq!(collection.inner, Vec::from([0, 2, 4, 5, 6, 8]));
+		assert_eq!(
+			collection.pos, 2,
+			"shouldn't move the cursor when inserting after it"
+		);
+
+		assert_eq!(collection.sorted_insert(1), 1);
+		assert_eq!(collection.inner, Vec::from([0, 1, 2, 4, 5, 6, 8]));
+		assert_eq!(
+			collection.pos, 3,
+			"should move the cursor forward when inserting before it"
+		);
+
+		assert_eq!(collection.sorted_insert(2), 3);
+		assert_eq!(collection.inner, Vec::from([0, 1, 2, 2, 4, 5, 6, 8]));
+		assert_eq!(
+			collection.pos, 4,
+			"should move the cursor forward when inserting at it"
+		);
+
+		collection.pos = collection.inner.len();
+		assert_eq!(collection.sorted_insert(9), 8);
+		assert_eq!(
+			collection.pos, 9,
+			"should keep the cursor at the end when inserting at the end"
+		);
+	}
+
+	#[test]
+	fn insert_sorted_batch() {
+		let mut collection = CollectionCursor::new(Vec::from([0, 2, 4, 6, 8]));
+		collection.pos = 2;
+
+		collection.insert_sorted_batch([7, 1, 4]);
+		assert_eq!(
+			collection.inner,
+			Vec::from([0, 1, 2, 4, 4, 6, 7, 8]),
+			"should keep the collection sorted"
+		);
+		assert_eq!(
+			collection.pos, 3,
+			"should move the cursor forward by the number of items inserted before it"
+		);
+		assert_eq!(collection.get_item_at_cursor(), Some(&4));
+
+		collection.pos = collection.inner.len();
+		collection.insert_sorted_batch([3, 9]);
+		assert_eq!(
+			collection.pos,
+			collection.inner.len(),
+			"should keep the cursor at the end when it was at the end"
+		);
+	}
+
+	#[test]
+	fn drain_while_from_cursor() {
+		let mut collection = CollectionCursor::new(Vec::from([1, 2, 4, 6, 7, 8]));
+		collection.pos = 1;
+
+		let drained = collection.drain_while_from_cursor(|item| item % 2 == 0);
+		assert_eq!(
+			drained,
+			Vec::from([2, 4, 6]),
+			"should return the drained run"
+		);
+		assert_eq!(
+			collection.inner,
+			Vec::from([1, 7, 8]),
+			"should remove the drained run from the collection"
+		);
+		assert_eq!(collection.pos, 1, "shouldn't move the cursor");
+		assert_eq!(collection.get_item_at_cursor(), Some(&7));
+
+		let drained = collection.drain_while_from_cursor(|item| item % 2 == 0);
+		assert!(
+			drained.is_empty(),
+			"shouldn't drain anything if the item at the cursor doesn't match"
+		);
+		assert_eq!(collection.inner, Vec::from([1, 7, 8]));
+	}
+
+	#[test]
+	fn drain_while_into() {
+		let mut collection = CollectionCursor::new(Vec::from([2, 4, 5, 6]));
+		let mut sink = Vec::from([0]);
+
+		assert_eq!(
+			collection.drain_while_into(|item| item % 2 == 0, &mut sink),
+			2
+		);
+		assert_eq!(
+			sink,
+			Vec::from([0, 2, 4]),
+			"should append the drained run to the sink, in order"
+		);
+		assert_eq!(collection.inner, Vec::from([5, 6]));
+		assert_eq!(collection.pos, 0, "shouldn't move the cursor");
+
+		assert_eq!(
+			collection.drain_while_into(|item| item % 2 == 0, &mut sink),
+			0,
+			"shouldn't drain anything if the item at the cursor doesn't match"
+		);
+		assert_eq!(sink, Vec::from([0, 2, 4]));
+	}
+
+	#[test]
+	fn stable_partition_from_cursor() {
+		let mut collection = self::test_collection();
+		collection.pos = 2;
+
+		assert_eq!(
+			collection.stable_partition_from_cursor(|item| item % 2 == 0),
+			6,
+			"should return the index of the first non-matching item"
+		);
+		assert_eq!(
+			collection.inner,
+			Vec::from([0, 1, 2, 4, 8, 6, 3, 5, 9, 7]),
+			"should keep the relative order of each group, and leave the prefix untouched"
+		);
+		assert_eq!(collection.pos, 2, "shouldn't move the cursor");
+	}
+
+	#[test]
+	fn make_contiguous() {
+		let mut deque = VecDeque::from([2, 3, 4]);
+		deque.push_front(1);
+		deque.push_front(0);
+		let mut collection = CollectionCursor::new(deque);
+		collection.pos = 1;
+
+		let slice = collection.make_contiguous();
+		assert_eq!(slice, &[0, 1, 2, 3, 4], "should return the items in order");
+		assert_eq!(
+			collection.inner.as_slices(),
+			(&[0, 1, 2, 3, 4][..], &[][..]),
+			"should store the items contiguously"
+		);
+		assert_eq!(collection.get_item_at_cursor(), Some(&1));
+
+		let pos = collection.position();
+		let windows = collection.make_contiguous()[pos..]
+			.windows(2)
+			.map(|window| window[0] + window[1])
+			.collect::<Vec<_>>();
+		assert_eq!(
+			windows,
+			Vec::from([3, 5, 7]),
+			"should allow taking windows of the returned slice"
+		);
+		assert!(
+			matches!(collection.remaining_as_cow(), Cow::Borrowed(&[1, 2, 3, 4])),
+			"should borrow the remaining items once contiguous"
+		);
+	}
+
+	#[test]
+	fn drain_from_cursor() {
+		let mut collection = CollectionCursor::new(Vec::from([0, 1, 2, 3, 4, 5, 6]));
+		collection.pos = 2;
+
+		let mut drained = collection.drain_from_cursor();
+		assert_eq!(drained.next(), Some(2));
+		assert_eq!(drained.next(), Some(3));
+		drop(drained);
+
+		assert_eq!(
+			collection.inner,
+			Vec::from([0, 1]),
+			"should remove every item from the cursor onwards, even if not all were consumed"
+		);
+		assert!(
+			collection.is_cursor_at_end(),
+			"should leave the cursor at the end"
+		);
+	}
+
+	#[test]
+	fn drain_all() {
+		let mut collection = CollectionCursor::new(Vec::from([0, 1, 2, 3, 4]));
+		collection.pos = 3;
+
+		assert_eq!(
+			collection.drain_all().collect::<Vec<_>>(),
+			Vec::from([0, 1, 2, 3, 4]),
+			"should yield every item, regardless of the cursor"
+		);
+		assert!(collection.inner.is_empty(), "should empty the collection");
+		assert_eq!(collection.pos, 0, "should return the cursor to the start");
+	}
+
+	#[test]
+	fn remove_range() {
+		let mut collection = self::test_collection();
+		collection.pos = 5;
+		assert_eq!(collection.remove_range(1..3), Some(Vec::from([1, 2])));
+		assert_eq!(collection.inner, Vec::from([0, 3, 4, 5, 9, 8, 7, 6]));
+		assert_eq!(
+			collection.pos, 3,
+			"should move the cursor back when removing before it"
+		);
+		assert_eq!(collection.get_item_at_cursor(), Some(&5));
+
+		let mut collection = self::test_collection();
+		collection.pos = 5;
+		assert_eq!(collection.remove_range(4..7), Some(Vec::from([4, 5, 9])));
+		assert_eq!(
+			collection.pos, 4,
+			"should move the cursor to the start of the range when removing around it"
+		);
+
+		let mut collection = self::test_collection();
+		collection.pos = 5;
+		assert_eq!(collection.remove_range(7..10), Some(Vec::from([8, 7, 6])));
+		assert_eq!(
+			collection.pos, 5,
+			"shouldn't move the cursor when removing after it"
+		);
+
+		let mut collection = self::test_collection();
+		collection.pos = 5;
+		assert_eq!(
+			collection.remove_range(8..11),
+			None,
+			"should return `None` when the range is out-of-bounds"
+		);
+		#[expect(
+			clippy::reversed_empty_ranges,
+			reason = "testing that decreasing ranges are rejected"
+		)]
+		let decreasing = 3..2;
+		assert_eq!(
+			collection.remove_range(decreasing),
+			None,
+			"should return `None` when the range is decreasing"
+		);
+		assert_eq!(collection.inner.len(), 10, "shouldn't remove anything");
+		assert_eq!(collection.pos, 5, "shouldn't move the cursor");
+	}
+
+	#[test]
+	fn prepend_from() {
+		let mut collection = CollectionCursor::new(Vec::from([3, 4, 5]));
+		collection.pos = 1;
+		let mut other = CollectionCursor::new(Vec::from([0, 1, 2]));
+		other.pos = 2;
+
+		collection.prepend_from(other);
+		assert_eq!(
+			collection.inner,
+			Vec::from([0, 1, 2, 3, 4, 5]),
+			"should insert all of the other collection's items at the start"
+		);
+		assert_eq!(
+			collection.pos, 4,
+			"should move the cursor forward by the number of items inserted"
+		);
+		assert_eq!(collection.get_item_at_cursor(), Some(&4));
+	}
+
+	#[test]
+	fn collapse_run_at_cursor() {
+		let mut collection = CollectionCursor::new(Vec::from([1, 0, 0, 0, 2, 0]));
+		collection.pos = 1;
+
+		assert_eq!(
+			collection.collapse_run_at_cursor(|item| *item == 0, 9),
+			3,
+			"should return the length of the collapsed run"
+		);
+		assert_eq!(
+			collection.inner,
+			Vec::from([1, 9, 2, 0]),
+			"should replace the run with the replacement"
+		);
+		assert_eq!(collection.pos, 1, "shouldn't move the cursor");
+
+		assert_eq!(
+			collection.collapse_run_at_cursor(|item| *item == 0, 9),
+			0,
+			"shouldn't collapse anything if the item at the cursor doesn't match"
+		);
+		assert_eq!(collection.inner, Vec::from([1, 9, 2, 0]));
+	}
+
+	#[test]
+	fn into_vec_deque_cursor() {
+		let mut collection = CollectionCursor::new(Vec::from([0, 1, 2, 3, 4]));
+		collection.pos = 3;
+
+		let collection = collection.into_vec_deque_cursor();
+		assert_eq!(collection.inner, VecDeque::from([0, 1, 2, 3, 4]));
+		assert_eq!(collection.pos, 3, "should keep the cursor's position");
+	}
+
+	#[test]
+	fn clone_halves() {
+		let mut collection = self::test_collection();
+		collection.pos = 4;
+
+		let (before, after) = collection.clone_halves();
+		assert_eq!(before, Vec::from([0, 1, 2, 3]));
+		assert_eq!(after, Vec::from([4, 5, 9, 8, 7, 6]));
+		assert_eq!(collection.pos, 4, "shouldn't move the cursor");
+		assert_eq!(
+			collection.inner.len(),
+			10,
+			"shouldn't modify the collection"
+		);
+	}
+
+	#[test]
+	fn remaining_as_cow() {
+		let mut collection = CollectionCursor::new(Vec::from([0, 1, 2, 3, 4]));
+		collection.pos = 2;
+
+		let remaining = collection.remaining_as_cow();
+		assert!(
+			matches!(remaining, Cow::Borrowed(_)),
+			"should borrow from a contiguous collection"
+		);
+		assert_eq!(*remaining, [2, 3, 4]);
+
+		let mut deque = VecDeque::from([2, 3, 4]);
+		deque.push_front(1);
+		deque.push_front(0);
+		let mut collection = CollectionCursor::new(deque);
+		collection.pos = 2;
+
+		let remaining = collection.remaining_as_cow();
+		assert!(
+			matches!(remaining, Cow::Owned(_)),
+			"should clone from a non-contiguous collection"
+		);
+		assert_eq!(*remaining, [2, 3, 4]);
+	}
+
+	#[test]
+	fn try_map_remaining() {
+		let mut collection = CollectionCursor::new(Vec::from(["zero", "1", "2", "three", "4"]));
+		collection.pos = 1;
+
+		let mut mapped = 0;
+		let res = collection.try_map_remaining(|item| {
+			mapped += 1;
+			item.parse::<i32>()
+		});
+		assert!(res.is_err(), "should return the first error");
+		assert_eq!(mapped, 3, "should stop mapping after the first error");
+
+		collection.pos = 4;
+		assert_eq!(
+			collection.try_map_remaining(|item| item.parse::<i32>()),
+			Ok(Vec::from([4])),
+			"should collect the mapped items if none fail"
+		);
+		assert_eq!(collection.pos, 4, "shouldn't move the cursor");
+	}
+
+	#[test]
+	fn consume_exact() {
+		let mut collection = self::test_collection();
+		collection.pos = 2;
+
+		assert_eq!(collection.consume_exact(3), Some(Vec::from([2, 3, 4])));
+		assert_eq!(collection.pos, 5, "should move past the consumed items");
+
+		assert_eq!(collection.consume_exact(0), Some(Vec::new()));
+		assert_eq!(collection.pos, 5, "shouldn't move when consuming nothing");
+
+		assert_eq!(
+			collection.consume_exact(6),
+			None,
+			"should fail if fewer than `n` items remain"
+		);
+		assert_eq!(collection.pos, 5, "shouldn't move after failing");
+
+		assert_eq!(
+			collection.consume_exact(5),
+			Some(Vec::from([5, 9, 8, 7, 6]))
+		);
+		assert_eq!(collection.pos, 10);
+	}
+
+	#[test]
+	fn count_distinct_from_cursor() {
+		let mut collection = CollectionCursor::new(Vec::from([3, 1, 3, 2, 1, 3, 4]));
+
+		assert_eq!(collection.count_distinct_from_cursor(), 4);
+		assert_eq!(collection.pos, 0, "shouldn't move the cursor");
+
+		collection.pos = 3;
+		assert_eq!(
+			collection.count_distinct_from_cursor(),
+			4,
+			"should only count items at or after the cursor"
+		);
+
+		collection.pos = 4;
+		assert_eq!(collection.count_distinct_from_cursor(), 3);
+
+		collection.seek_to_end();
+		assert_eq!(collection.count_distinct_from_cursor(), 0);
+	}
+
+	#[test]
+	fn checkpoints() {
+		let mut collection = self::test_collection();
+
+		collection.pos = 2;
+		collection.set_checkpoint("first");
+		collection.pos = 8;
+		collection.set_checkpoint("second");
+
+		assert!(collection.goto_checkpoint("first"));
+		assert_eq!(collection.pos, 2, "should move to the named position");
+		assert!(collection.goto_checkpoint("second"));
+		assert_eq!(collection.pos, 8, "should move to the named position");
+
+		assert!(
+			!collection.goto_checkpoint("third"),
+			"should return `false` for an unknown name"
+		);
+		assert_eq!(collection.pos, 8, "shouldn't move for an unknown name");
+
+		collection.inner.truncate(5);
+		collection.pos = 0;
+		assert!(collection.goto_checkpoint("second"));
+		assert_eq!(
+			collection.pos, 5,
+			"should clamp a stale position to the end of the collection"
+		);
+	}
+
+	/// A [`Hasher`] which records every byte written to it, so that two hashes can be compared
+	/// exactly.
+	#[derive(Default)]
+	struct RecordingHasher(Vec<u8>);
+
+	impl Hasher for RecordingHasher {
+		fn finish(&self) -> u64 {
+			0
+		}
+
+		fn write(&mut self, bytes: &[u8]) {
+			self.0.extend_from_slice(bytes);
+		}
+	}
+
+	fn hash_bytes<T: Hash>(value: &T) -> Vec<u8> {
+		let mut hasher = RecordingHasher::default();
+		value.hash(&mut hasher);
+		hasher.0
+	}
+
+	#[test]
+	fn checkpoints_are_ignored_by_comparisons() {
+		let collection = CollectionCursor::new(Vec::from([0, 1, 2]));
+		let mut with_checkpoint = collection.clone();
+		with_checkpoint.set_checkpoint("checkpoint");
+
+		assert_eq!(collection, with_checkpoint);
+		assert_eq!(collection.cmp(&with_checkpoint), Ordering::Equal);
+		assert_eq!(
+			self::hash_bytes(&collection),
+			self::hash_bytes(&with_checkpoint)
+		);
+	}
+
+	#[test]
+	fn position_stack_is_ignored_by_comparisons() {
+		let collection = CollectionCursor::new(Vec::from([0, 1, 2]));
+		let mut with_saved_position = collection.clone();
+		with_saved_position.push_position();
+
+		assert_eq!(collection, with_saved_position);
+		assert_eq!(collection.cmp(&with_saved_position), Ordering::Equal);
+		assert_eq!(
+			self::hash_bytes(&collection),
+			self::hash_bytes(&with_saved_position)
+		);
+	}
+
+	#[test]
+	fn marks() {
+		let mut collection = self::test_collection();
+		assert!(collection.marks().is_empty());
+
+		for pos in [2, 5, 9] {
+			collection.pos = pos;
+			collection.push_position();
+		}
+		assert_eq!(
+			collection.marks(),
+			&[2, 5, 9],
+			"should return the saved positions in push order"
+		);
+
+		collection.clear_marks();
+		assert!(
+			collection.marks().is_empty(),
+			"should forget every saved position"
+		);
+		assert_eq!(collection.pos, 9, "shouldn't move the cursor");
+		assert_eq!(collection.pop_position(), None);
+	}
+
+	#[test]
+	fn position_stack() {
+		let mut collection = self::test_collection();
+
+		collection.pos = 2;
+		collection.push_position();
+		collection.pos = 5;
+		collection.push_position();
+		collection.pos = 9;
+
+		assert_eq!(
+			collection.pop_position(),
+			Some(5),
+			"should pop in LIFO order"
+		);
+		assert_eq!(collection.pos, 5, "should move to the popped position");
+		assert_eq!(
+			collection.pop_position(),
+			Some(2),
+			"should pop in LIFO order"
+		);
+		assert_eq!(
+			collection.pop_position(),
+			None,
+			"should be empty afterwards"
+		);
+		assert_eq!(collection.pos, 2, "shouldn't move when nothing is saved");
+
+		collection.pos = 8;
+		collection.push_position();
+		collection.inner.truncate(4);
+		assert_eq!(
+			collection.pop_position(),
+			Some(4),
+			"should clamp a stale position to the end of the collection"
+		);
+
+		collection.pos = 3;
+		collection.push_position();
+		collection.reset_position();
+		assert_eq!(
+			collection.pop_position(),
+			Some(3),
+			"should keep saved positions after resetting the position"
+		);
+	}
+}
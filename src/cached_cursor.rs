@@ -0,0 +1,226 @@
+use core::{
+	cell::Cell,
+	ops::{Deref, DerefMut},
+};
+
+use crate::{CollectionCursor, IndexableCollection};
+
+/// A wrapper around a [`CollectionCursor`] which memoizes whether the cursor points at an item, so
+/// that repeated calls to [`Self::get_item_at_cursor()`] only check the cursor against the
+/// collection's bounds once.
+///
+/// Once the cursor is known to be out-of-bounds, reads return `None` without accessing the
+/// collection at all. Otherwise, reads return a reference into the collection, so no item is ever
+/// copied or cloned. Any mutable access to the wrapped cursor (which includes seeking, as every
+/// method that moves the cursor takes `&mut self`) invalidates the cache, so a stale result will
+/// never be returned.
+///
+/// All other methods of [`CollectionCursor`] are available through [`Deref`] and [`DerefMut`].
+#[derive(Clone, Debug)]
+pub struct CachedCursor<Tape: IndexableCollection> {
+	/// The wrapped cursor.
+	cursor: CollectionCursor<Tape>,
+	/// Whether an item exists at the cursor, or `None` if that hasn't been checked yet. This is
+	/// filled on the first call to [`Self::get_item_at_cursor()`], and emptied on any mutable
+	/// access to `cursor`.
+	in_bounds: Cell<Option<bool>>,
+}
+
+impl<Tape: IndexableCollection> CachedCursor<Tape> {
+	/// Creates a new `CachedCursor` wrapping the provided cursor. The cache starts out empty.
+	pub fn new(cursor: CollectionCursor<Tape>) -> Self {
+		Self {
+			cursor,
+			in_bounds: Cell::new(None),
+		}
+	}
+
+	/// Unwraps this `CachedCursor`, returning the wrapped cursor.
+	pub fn into_cursor(self) -> CollectionCursor<Tape> {
+		self.cursor
+	}
+
+	/// Returns a reference to the element pointed at by the cursor.
+	///
+	/// Returns `None` if `self.position() >= self.get_ref().len()`.
+	///
+	/// The first call after creating the `CachedCursor` (or after the wrapped cursor was mutably
+	/// accessed) records whether the item exists. If it doesn't, subsequent calls return `None`
+	/// without accessing the collection.
+	pub fn get_item_at_cursor(&self) -> Option<&Tape::Item> {
+		if self.in_bounds.get() == Some(false) {
+			return None;
+		}
+
+		let item = self.cursor.get_item_at_cursor();
+		self.in_bounds.set(Some(item.is_some()));
+		item
+	}
+}
+
+impl<Tape: IndexableCollection> Deref for CachedCursor<Tape> {
+	type Target = CollectionCursor<Tape>;
+
+	fn deref(&self) -> &Self::Target {
+		&self.cursor
+	}
+}
+
+impl<Tape: IndexableCollection> DerefMut for CachedCursor<Tape> {
+	fn deref_mut(&mut self) -> &mut Self::Target {
+		// We can't know what the caller will do with the cursor, so assume the worst
+		self.in_bounds.take();
+		&mut self.cursor
+	}
+}
+
+impl<Tape: IndexableCollection> From<CollectionCursor<Tape>> for CachedCursor<Tape> {
+	fn from(value: CollectionCursor<Tape>) -> Self {
+		Self::new(value)
+	}
+}
+
+#[cfg(test)]
+mod cached_cursor_tests {
+	extern crate alloc;
+
+	use alloc::vec::Vec;
+	use core::cell::Cell;
+
+	use super::*;
+	use crate::{IndexableCollectionMut, SeekFrom, collection_cursor_tests::test_vec};
+
+	/// A collection which counts how many times an item has been looked up.
+	#[derive(Debug)]
+	struct CountingVec {
+		items: Vec<i32>,
+		lookups: Cell<usize>,
+	}
+
+	impl CountingVec {
+		fn new() -> Self {
+			Self {
+				items: self::test_vec(),
+				lookups: Cell::new(0),
+			}
+		}
+	}
+
+	impl IndexableCollection for CountingVec {
+		type Item = i32;
+
+		fn len(&self) -> usize {
+			self.items.len()
+		}
+
+		fn get_item(&self, index: usize) -> Option<&Self::Item> {
+			self.lookups.set(self.lookups.get() + 1);
+			self.items.get(index)
+		}
+	}
+
+	impl IndexableCollectionMut for CountingVec {
+		fn get_item_mut(&mut self, index: usize) -> Option<&mut Self::Item> {
+			self.items.get_mut(index)
+		}
+
+		fn set_item(&mut self, index: usize, element: Self::Item) {
+			self.items[index] = element;
+		}
+	}
+
+	fn test_cursor() -> CachedCursor<CountingVec> {
+		CachedCursor::new(CollectionCursor::new(CountingVec::new()))
+	}
+
+	#[test]
+	fn repeated_reads_past_end_look_up_once() {
+		const READS: usize = 10_000;
+
+		let mut uncached = CollectionCursor::new(CountingVec::new());
+		uncached.seek_to_end();
+		for _ in 0..READS {
+			assert_eq!(uncached.get_item_at_cursor(), None);
+		}
+		assert_eq!(uncached.get_ref().lookups.get(), READS);
+
+		let mut cursor = self::test_cursor();
+		cursor.seek_to_end();
+		for _ in 0..READS {
+			assert_eq!(cursor.get_item_at_cursor(), None);
+		}
+		assert_eq!(
+			cursor.get_ref().lookups.get(),
+			1,
+			"should only check the bounds once, no matter how many times the cursor is read"
+		);
+	}
+
+	#[test]
+	fn reads_borrow_from_collection() {
+		let cursor = self::test_cursor();
+
+		for _ in 0..2 {
+			assert!(
+				core::ptr::eq(
+					cursor.get_item_at_cursor().unwrap(),
+					&cursor.get_ref().items[0]
+				),
+				"should return a reference into the collection, rather than a copy"
+			);
+		}
+
+		/// An item which can't be cloned.
+		#[derive(Debug, PartialEq)]
+		struct NotClone(i32);
+		let cursor = CachedCursor::new(CollectionCursor::new(Vec::from([NotClone(1)])));
+		assert_eq!(
+			cursor.get_item_at_cursor(),
+			Some(&NotClone(1)),
+			"should work for items which can't be cloned"
+		);
+	}
+
+	#[test]
+	fn invalidates_on_seek() {
+		let mut cursor = self::test_cursor();
+		assert_eq!(cursor.get_item_at_cursor(), Some(&0));
+
+		cursor.seek(SeekFrom::Start(6)).unwrap();
+		assert_eq!(
+			cursor.get_item_at_cursor(),
+			Some(&9),
+			"should return the item at the new position after a seek"
+		);
+
+		cursor.seek_to_end();
+		assert_eq!(
+			cursor.get_item_at_cursor(),
+			None,
+			"should return `None` after seeking to the end"
+		);
+		assert_eq!(cursor.get_ref().lookups.get(), 3);
+	}
+
+	#[test]
+	fn invalidates_on_set_item_at_cursor() {
+		let mut cursor = self::test_cursor();
+		assert_eq!(cursor.get_item_at_cursor(), Some(&0));
+
+		cursor.set_item_at_cursor(52345);
+		assert_eq!(
+			cursor.get_item_at_cursor(),
+			Some(&52345),
+			"should return the new item after it was set"
+		);
+	}
+
+	#[test]
+	fn into_cursor() {
+		let cursor = self::test_cursor();
+		assert_eq!(cursor.get_item_at_cursor(), Some(&0));
+
+		let cursor = cursor.into_cursor();
+		assert_eq!(cursor.get_ref().items, CountingVec::new().items);
+	}
+}
@@ -0,0 +1,97 @@
+use core::iter::FusedIterator;
+
+use crate::{CollectionCursor, IndexableCollectionResizable};
+
+/// An iterator which removes and yields the items of a collection, starting at the cursor.
+///
+/// This is created by [`CollectionCursor::drain_items_from_cursor()`]. When dropped, any items
+/// which were not yielded are removed as well.
+#[derive(Debug)]
+pub struct DrainItemsFromCursor<'a, Tape: IndexableCollectionResizable> {
+	/// The cursor whose collection is being drained. Its position is the index of the next item
+	/// to yield.
+	cursor: &'a mut CollectionCursor<Tape>,
+}
+
+impl<'a, Tape: IndexableCollectionResizable> DrainItemsFromCursor<'a, Tape> {
+	/// Creates an iterator which drains the items of `cursor`'s collection, from the cursor to the
+	/// end of the collection. The cursor is clamped to the collection's bounds first.
+	pub(crate) fn new(cursor: &'a mut CollectionCursor<Tape>) -> Self {
+		cursor.clamp_to_end();
+		Self { cursor }
+	}
+}
+
+impl<Tape: IndexableCollectionResizable> Iterator for DrainItemsFromCursor<'_, Tape> {
+	type Item = Tape::Item;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		self.cursor.remove_item_at_cursor()
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let remaining = self.cursor.remaining();
+		(remaining, Some(remaining))
+	}
+}
+
+impl<Tape: IndexableCollectionResizable> ExactSizeIterator for DrainItemsFromCursor<'_, Tape> {}
+
+// Once the cursor reaches the end of the collection, there is nothing left to remove.
+impl<Tape: IndexableCollectionResizable> FusedIterator for DrainItemsFromCursor<'_, Tape> {}
+
+impl<Tape: IndexableCollectionResizable> Drop for DrainItemsFromCursor<'_, Tape> {
+	fn drop(&mut self) {
+		// Remove from the back, so that collections like `Vec` don't need to shift the remaining
+		// items on every removal
+		let start = self.cursor.pos;
+		while self.cursor.inner.len() > start {
+			let last_index = self.cursor.inner.len() - 1;
+			if self.cursor.inner.remove_item(last_index).is_none() {
+				break;
+			}
+		}
+	}
+}
+
+#[cfg(test)]
+mod drain_items_from_cursor_tests {
+	extern crate alloc;
+
+	use alloc::{collections::VecDeque, vec::Vec};
+
+	use crate::CollectionCursor;
+
+	#[test]
+	fn yields_items_from_cursor() {
+		let mut collection = CollectionCursor::new(VecDeque::from([0, 1, 2, 3, 4]));
+		collection.seek_forward_one();
+
+		assert_eq!(
+			collection.drain_items_from_cursor().collect::<Vec<_>>(),
+			Vec::from([1, 2, 3, 4])
+		);
+		assert_eq!(collection.get_ref(), &VecDeque::from([0]));
+		assert!(
+			collection.is_cursor_at_end(),
+			"should leave the cursor at the end"
+		);
+	}
+
+	#[test]
+	fn removes_unconsumed_items_on_drop() {
+		let mut collection = CollectionCursor::new(VecDeque::from([0, 1, 2, 3, 4]));
+
+		let mut drained = collection.drain_items_from_cursor();
+		assert_eq!(drained.len(), 5);
+		assert_eq!(drained.next(), Some(0));
+		assert_eq!(drained.next(), Some(1));
+		drop(drained);
+
+		assert!(
+			collection.get_ref().is_empty(),
+			"should remove every item from the cursor onwards, even if not all were consumed"
+		);
+		assert_eq!(collection.position(), 0);
+	}
+}
@@ -0,0 +1,129 @@
+use core::ops::Deref;
+
+use crate::{CollectionCursor, IndexableCollectionMut, IndexableCollectionResizable};
+
+/// A guard returned by [`CollectionCursor::edit()`], which allows modifying the collection while
+/// ensuring the cursor never ends up out-of-bounds.
+///
+/// After every modification made through the guard, the cursor is clamped back to within the
+/// collection's bounds (`0 <= position <= len`). As such, unlike modifying the collection through
+/// [`CollectionCursor::get_mut()`], there is never a point where the cursor is left invalid.
+///
+/// Read-only methods of [`CollectionCursor`] are available through [`Deref`].
+#[derive(Debug)]
+pub struct EditGuard<'a, Tape: IndexableCollectionMut> {
+	/// The cursor being edited.
+	cursor: &'a mut CollectionCursor<Tape>,
+}
+
+impl<'a, Tape: IndexableCollectionMut> EditGuard<'a, Tape> {
+	/// Creates a new `EditGuard` over `cursor`, clamping the cursor in case it is already
+	/// out-of-bounds.
+	pub(crate) fn new(cursor: &'a mut CollectionCursor<Tape>) -> Self {
+		cursor.clamp_to_end();
+		Self { cursor }
+	}
+
+	/// Sets the item at index `index` to `element`, then clamps the cursor.
+	///
+	/// # Panics
+	/// Panics if the set operation panics. The circumstances for a panic are defined by the inner
+	/// collection, but will usually occur if `index >= self.get_ref().len()`.
+	pub fn set_item(&mut self, index: usize, element: Tape::Item) {
+		self.cursor.inner.set_item(index, element);
+		self.cursor.clamp_to_end();
+	}
+}
+
+impl<Tape: IndexableCollectionResizable> EditGuard<'_, Tape> {
+	/// Inserts `element` at index `index`, moving the item at the index and all items after it one
+	/// index forward, then clamps the cursor. The cursor is not moved to follow the shifted items.
+	///
+	/// # Panics
+	/// Panics if the insert operation panics. The circumstances for a panic are defined by the
+	/// inner collection, but will usually occur if `index > self.get_ref().len()`.
+	pub fn insert_item(&mut self, index: usize, element: Tape::Item) {
+		self.cursor.inner.insert_item(index, element);
+		self.cursor.clamp_to_end();
+	}
+
+	/// Removes and returns the item at index `index`, then clamps the cursor. If the cursor was at
+	/// the end of the collection, it is moved back to the new end.
+	///
+	/// Returns `None` if no item exists at `index`.
+	pub fn remove_item(&mut self, index: usize) -> Option<Tape::Item> {
+		let item = self.cursor.inner.remove_item(index);
+		self.cursor.clamp_to_end();
+		item
+	}
+}
+
+impl<Tape: IndexableCollectionMut> Deref for EditGuard<'_, Tape> {
+	type Target = CollectionCursor<Tape>;
+
+	fn deref(&self) -> &Self::Target {
+		self.cursor
+	}
+}
+
+#[cfg(test)]
+mod edit_guard_tests {
+	extern crate alloc;
+
+	use alloc::vec::Vec;
+
+	use crate::CollectionCursor;
+
+	#[test]
+	fn removals_keep_position_valid() {
+		let mut cursor = CollectionCursor::new(Vec::from([0, 1, 2, 3, 4]));
+		cursor.seek_to_end();
+
+		let mut guard = cursor.edit();
+		for expected_len in (0..5).rev() {
+			assert!(guard.remove_item(0).is_some());
+			assert_eq!(guard.get_ref().len(), expected_len);
+			assert_eq!(
+				guard.position(),
+				expected_len,
+				"should clamp the cursor to the new end after each removal"
+			);
+			assert!(guard.position_is_valid());
+		}
+
+		assert_eq!(
+			guard.remove_item(0),
+			None,
+			"should remove nothing once empty"
+		);
+		assert_eq!(guard.position(), 0);
+	}
+
+	#[test]
+	fn set_and_insert() {
+		let mut cursor = CollectionCursor::new(Vec::from([0, 1, 2]));
+		cursor.seek_to_end();
+
+		let mut guard = cursor.edit();
+		guard.set_item(1, 10);
+		guard.insert_item(0, 20);
+		assert_eq!(guard.position(), 3, "shouldn't move the cursor on insert");
+		assert!(guard.position_is_valid());
+
+		assert_eq!(cursor.get_ref(), &Vec::from([20, 0, 10, 2]));
+	}
+
+	#[test]
+	fn clamps_on_creation() {
+		let mut cursor = CollectionCursor::new(Vec::from([0, 1, 2]));
+		cursor.seek_to_end();
+		cursor.get_mut().truncate(1);
+
+		let guard = cursor.edit();
+		assert_eq!(
+			guard.position(),
+			1,
+			"should clamp a cursor which was already out-of-bounds"
+		);
+	}
+}
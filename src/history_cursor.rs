@@ -0,0 +1,224 @@
+extern crate alloc;
+
+use alloc::collections::VecDeque;
+use core::{fmt, ops::Deref};
+
+use crate::{CollectionCursor, IndexableCollection, OutOfBoundsError, SeekFrom};
+
+/// A wrapper around a [`CollectionCursor`] which records the position of the cursor before each
+/// seek, so that the cursor can later jump back through those positions.
+///
+/// Only the most recent `capacity` positions are kept. Once the history is full, recording another
+/// position forgets the oldest one.
+///
+/// Only seeks made through this wrapper's own methods are recorded. The read-only methods of
+/// [`CollectionCursor`] are available through [`Deref`], while [`Self::cursor_mut()`] can be used
+/// to access the wrapped cursor without recording anything.
+#[derive(Clone)]
+pub struct HistoryCursor<Tape> {
+	/// The wrapped cursor.
+	cursor: CollectionCursor<Tape>,
+	/// The recorded positions, from oldest to newest.
+	history: VecDeque<usize>,
+	/// The maximum number of positions that `history` will hold.
+	capacity: usize,
+}
+
+impl<Tape> HistoryCursor<Tape> {
+	/// Creates a new `HistoryCursor` wrapping the provided cursor, which will remember up to
+	/// `capacity` positions. The history starts out empty.
+	pub fn new(cursor: CollectionCursor<Tape>, capacity: usize) -> Self {
+		Self {
+			cursor,
+			history: VecDeque::new(),
+			capacity,
+		}
+	}
+
+	/// Gets a mutable reference to the wrapped cursor.
+	///
+	/// Seeks made through this reference are not recorded.
+	pub fn cursor_mut(&mut self) -> &mut CollectionCursor<Tape> {
+		&mut self.cursor
+	}
+
+	/// Unwraps this `HistoryCursor`, returning the wrapped cursor. The history is discarded.
+	pub fn into_cursor(self) -> CollectionCursor<Tape> {
+		self.cursor
+	}
+
+	/// Records `pos` as the newest position in the history, forgetting the oldest position if the
+	/// history is full.
+	fn record(&mut self, pos: usize) {
+		if self.capacity == 0 {
+			return;
+		}
+
+		if self.history.len() >= self.capacity {
+			self.history.pop_front();
+		}
+		self.history.push_back(pos);
+	}
+}
+
+impl<Tape: IndexableCollection> HistoryCursor<Tape> {
+	/// Moves the cursor to a new index, recording the position it was at beforehand. The return
+	/// value is the same as the one returned for [`CollectionCursor::seek()`].
+	///
+	/// # Errors
+	/// Returns an [`OutOfBoundsError`] under the same circumstances as
+	/// [`CollectionCursor::seek()`]. Nothing is recorded in these cases.
+	///
+	/// # Panics
+	/// When the `strict-arithmetic` feature is enabled and debug assertions are on, this panics
+	/// under the same circumstances as [`CollectionCursor::seek()`].
+	pub fn seek(&mut self, pos: SeekFrom) -> Result<usize, OutOfBoundsError> {
+		let previous_pos = self.cursor.pos;
+		let res = self.cursor.seek(pos);
+
+		if res.is_ok() {
+			self.record(previous_pos);
+		}
+		res
+	}
+
+	/// Moves the cursor relative to the current position, recording the position it was at
+	/// beforehand. The return value is the same as the one returned for [`Self::seek()`].
+	///
+	/// This is a convenience method, equivalent to `self.seek(SeekFrom::Current(offset))`.
+	///
+	/// # Errors
+	/// Returns an [`OutOfBoundsError`] under the same circumstances as [`Self::seek()`].
+	///
+	/// # Panics
+	/// When the `strict-arithmetic` feature is enabled and debug assertions are on, this panics
+	/// under the same circumstances as [`Self::seek()`].
+	pub fn seek_relative(&mut self, offset: isize) -> Result<usize, OutOfBoundsError> {
+		self.seek(SeekFrom::Current(offset))
+	}
+
+	/// Moves the cursor back to the most recently recorded position, removing it from the history.
+	/// Returns the new position of the cursor, or `None` if the history is empty.
+	///
+	/// If the collection has shrunk since the position was recorded, the cursor is clamped to
+	/// `self.get_ref().len()`.
+	pub fn jump_back(&mut self) -> Option<usize> {
+		let pos = self.history.pop_back()?;
+
+		self.cursor.pos = pos.min(self.cursor.inner.len());
+		Some(self.cursor.pos)
+	}
+}
+
+// Implemented manually, as the `Debug` implementation of `CollectionCursor` requires the
+// collection to be an `IndexableCollection`.
+impl<Tape: IndexableCollection + fmt::Debug> fmt::Debug for HistoryCursor<Tape> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_struct("HistoryCursor")
+			.field("cursor", &self.cursor)
+			.field("history", &self.history)
+			.field("capacity", &self.capacity)
+			.finish()
+	}
+}
+
+impl<Tape> Deref for HistoryCursor<Tape> {
+	type Target = CollectionCursor<Tape>;
+
+	fn deref(&self) -> &Self::Target {
+		&self.cursor
+	}
+}
+
+#[cfg(test)]
+mod history_cursor_tests {
+	use alloc::vec::Vec;
+
+	use super::*;
+	use crate::collection_cursor_tests::test_collection;
+
+	fn test_cursor(capacity: usize) -> HistoryCursor<Vec<i32>> {
+		HistoryCursor::new(self::test_collection(), capacity)
+	}
+
+	#[test]
+	fn jump_back() {
+		let mut cursor = self::test_cursor(8);
+
+		cursor.seek(SeekFrom::Start(2)).unwrap();
+		cursor.seek(SeekFrom::End(-5)).unwrap();
+		cursor.seek_relative(3).unwrap();
+		assert_eq!(cursor.position(), 8);
+
+		assert_eq!(
+			cursor.jump_back(),
+			Some(5),
+			"should jump back in LIFO order"
+		);
+		assert_eq!(
+			cursor.jump_back(),
+			Some(2),
+			"should jump back in LIFO order"
+		);
+		assert_eq!(
+			cursor.jump_back(),
+			Some(0),
+			"should jump back in LIFO order"
+		);
+		assert_eq!(
+			cursor.jump_back(),
+			None,
+			"should return `None` after running out of history"
+		);
+		assert_eq!(cursor.position(), 0, "shouldn't move without any history");
+	}
+
+	#[test]
+	fn failed_seeks_are_not_recorded() {
+		let mut cursor = self::test_cursor(8);
+
+		cursor.seek(SeekFrom::Start(2)).unwrap();
+		assert!(cursor.seek(SeekFrom::Start(usize::MAX)).is_err());
+		assert_eq!(cursor.jump_back(), Some(0));
+		assert_eq!(cursor.jump_back(), None);
+	}
+
+	#[test]
+	fn capacity() {
+		let mut cursor = self::test_cursor(2);
+
+		cursor.seek(SeekFrom::Start(1)).unwrap();
+		cursor.seek(SeekFrom::Start(2)).unwrap();
+		cursor.seek(SeekFrom::Start(3)).unwrap();
+		assert_eq!(cursor.jump_back(), Some(2));
+		assert_eq!(cursor.jump_back(), Some(1));
+		assert_eq!(
+			cursor.jump_back(),
+			None,
+			"should have forgotten positions past its capacity"
+		);
+
+		let mut cursor = self::test_cursor(0);
+		cursor.seek(SeekFrom::Start(1)).unwrap();
+		assert_eq!(
+			cursor.jump_back(),
+			None,
+			"shouldn't record anything with a capacity of `0`"
+		);
+	}
+
+	#[test]
+	fn jump_back_clamps_to_collection() {
+		let mut cursor = self::test_cursor(8);
+
+		cursor.seek(SeekFrom::Start(9)).unwrap();
+		cursor.seek(SeekFrom::Start(0)).unwrap();
+		cursor.cursor_mut().get_mut().truncate(4);
+
+		assert_eq!(
+			cursor.jump_back(),
+			Some(4),
+			"should clamp the position to the end of the collection"
+		);
+	}
+}
@@ -0,0 +1,205 @@
+use core::iter::FusedIterator;
+
+use crate::{ContiguousCollection, IndexableCollection};
+
+/// An iterator over the items of a collection, starting at the cursor.
+///
+/// This is created by [`CollectionCursor::iter_from_cursor()`].
+///
+/// [`CollectionCursor::iter_from_cursor()`]: crate::CollectionCursor::iter_from_cursor
+#[derive(Debug)]
+pub struct IterFromCursor<'a, Tape> {
+	/// The collection being iterated over.
+	collection: &'a Tape,
+	/// The index of the next item to yield.
+	index: usize,
+	/// One index past the last item to yield.
+	end: usize,
+}
+
+impl<'a, Tape: IndexableCollection> IterFromCursor<'a, Tape> {
+	/// Creates an iterator over the items of `collection`, from `start` up to the end of the
+	/// collection.
+	pub(crate) fn new(collection: &'a Tape, start: usize) -> Self {
+		Self {
+			collection,
+			index: start,
+			end: collection.len(),
+		}
+	}
+}
+
+impl<'a, Tape: ContiguousCollection> IterFromCursor<'a, Tape> {
+	/// Returns a slice of the items that have yet to be yielded by this iterator.
+	pub fn as_remaining_slice(&self) -> &'a [Tape::Item] {
+		self.collection
+			.as_slice()
+			.get(self.index..self.end)
+			.unwrap_or_default()
+	}
+}
+
+// Implemented manually, as deriving `Clone` would require `Tape: Clone`, even though we only hold a
+// reference to it.
+impl<Tape> Clone for IterFromCursor<'_, Tape> {
+	fn clone(&self) -> Self {
+		Self {
+			collection: self.collection,
+			index: self.index,
+			end: self.end,
+		}
+	}
+}
+
+impl<'a, Tape: IndexableCollection> Iterator for IterFromCursor<'a, Tape> {
+	type Item = &'a Tape::Item;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.index >= self.end {
+			return None;
+		}
+
+		let item = self.collection.get_item(self.index);
+		// If the collection doesn't have an item here, consider ourselves exhausted, so that we
+		// stay fused
+		self.index = if item.is_some() {
+			self.index + 1
+		} else {
+			self.end
+		};
+		item
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let remaining = self.end.saturating_sub(self.index);
+		(remaining, Some(remaining))
+	}
+}
+
+impl<Tape: IndexableCollection> DoubleEndedIterator for IterFromCursor<'_, Tape> {
+	fn next_back(&mut self) -> Option<Self::Item> {
+		if self.index >= self.end {
+			return None;
+		}
+
+		let item = self.collection.get_item(self.end - 1);
+		// As with `next()`, consider ourselves exhausted if the collection doesn't have an item
+		// here
+		self.end = if item.is_some() {
+			self.end - 1
+		} else {
+			self.index
+		};
+		item
+	}
+}
+
+// `size_hint()` is exact, as long as the collection has an item at every index below its length.
+impl<Tape: IndexableCollection> ExactSizeIterator for IterFromCursor<'_, Tape> {}
+
+// Once `index` reaches `end`, it is never incremented again, so we will keep returning `None`.
+impl<Tape: IndexableCollection> FusedIterator for IterFromCursor<'_, Tape> {}
+
+#[cfg(test)]
+mod iter_from_cursor_tests {
+	extern crate alloc;
+
+	use alloc::vec::Vec;
+
+	use super::*;
+
+	#[test]
+	fn iterates_from_start() {
+		let collection = Vec::from([0, 1, 2, 3, 4]);
+
+		let items = IterFromCursor::new(&collection, 2).collect::<Vec<_>>();
+		assert_eq!(items, Vec::from([&2, &3, &4]));
+	}
+
+	#[test]
+	fn fused_after_exhaustion() {
+		let collection = Vec::from([0, 1, 2]);
+		let mut iter = IterFromCursor::new(&collection, 1);
+
+		assert_eq!(iter.next(), Some(&1));
+		assert_eq!(iter.next(), Some(&2));
+		for _ in 0..5 {
+			assert_eq!(
+				iter.next(),
+				None,
+				"should keep returning `None` after being exhausted"
+			);
+		}
+	}
+
+	#[test]
+	fn start_past_end() {
+		let collection = Vec::from([0, 1, 2]);
+		let mut iter = IterFromCursor::new(&collection, usize::MAX);
+
+		assert_eq!(iter.size_hint(), (0, Some(0)));
+		assert_eq!(iter.next(), None);
+	}
+
+	#[test]
+	fn exact_len() {
+		let collection = Vec::from([0, 1, 2, 3, 4]);
+		let mut iter = IterFromCursor::new(&collection, 2);
+
+		assert_eq!(iter.len(), 3, "should count the items from the start");
+		iter.next();
+		assert_eq!(iter.len(), 2, "should shrink as items are yielded");
+	}
+
+	#[test]
+	fn double_ended() {
+		let collection = Vec::from([0, 1, 2, 3, 4]);
+		let mut iter = IterFromCursor::new(&collection, 1);
+
+		assert_eq!(iter.next_back(), Some(&4));
+		assert_eq!(iter.next(), Some(&1));
+		assert_eq!(iter.next_back(), Some(&3));
+		assert_eq!(
+			iter.len(),
+			1,
+			"should count the items left between both ends"
+		);
+		assert_eq!(iter.next_back(), Some(&2));
+		assert_eq!(iter.next(), None, "should meet in the middle");
+		assert_eq!(iter.next_back(), None, "should meet in the middle");
+	}
+
+	#[test]
+	fn as_remaining_slice() {
+		let collection = Vec::from([0, 1, 2, 3, 4]);
+		let mut iter = IterFromCursor::new(&collection, 1);
+
+		assert_eq!(iter.next(), Some(&1));
+		assert_eq!(iter.next(), Some(&2));
+		assert_eq!(
+			iter.as_remaining_slice(),
+			&[3, 4],
+			"should only contain the items that haven't been yielded"
+		);
+
+		iter.by_ref().for_each(drop);
+		assert_eq!(iter.as_remaining_slice(), &[] as &[i32]);
+	}
+
+	#[test]
+	fn clone_resumes_independently() {
+		let collection = Vec::from([0, 1, 2, 3]);
+		let mut iter = IterFromCursor::new(&collection, 0);
+
+		assert_eq!(iter.next(), Some(&0));
+		let mut cloned = iter.clone();
+
+		assert_eq!(iter.collect::<Vec<_>>(), Vec::from([&1, &2, &3]));
+		assert_eq!(
+			cloned.next(),
+			Some(&1),
+			"should continue from where it was cloned"
+		);
+		assert_eq!(cloned.collect::<Vec<_>>(), Vec::from([&2, &3]));
+	}
+}
@@ -1,8 +1,44 @@
 #![no_std]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+#[cfg(feature = "alloc")]
+use alloc::{collections::BTreeMap, vec::Vec};
+use core::{
+	cmp::Ordering,
+	fmt,
+	hash::{Hash, Hasher},
+	iter::Rev,
+	ops::{Range, RangeInclusive},
+};
+
+#[cfg(feature = "alloc")]
+mod alloc_methods;
+mod cached_cursor;
+mod drain;
+mod edit_guard;
+#[cfg(feature = "alloc")]
+mod history_cursor;
+mod iter;
+#[cfg(feature = "std")]
+mod std_io;
 mod trait_impls_by_crate;
 
-#[derive(Clone, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub use cached_cursor::CachedCursor;
+pub use drain::DrainItemsFromCursor;
+pub use edit_guard::EditGuard;
+#[cfg(feature = "alloc")]
+pub use history_cursor::HistoryCursor;
+pub use iter::IterFromCursor;
+
+/// A cursor which wraps an indexable collection.
+///
+/// Cursors are compared, ordered, and hashed by their collection and then their position. Any
+/// recorded checkpoints or saved positions are not taken into account.
+#[derive(Clone, Default)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CollectionCursor<Tape> {
 	/// The underlying collection that the cursor will point into.
@@ -16,16 +52,38 @@ pub struct CollectionCursor<Tape> {
 	/// the pos back within the collection's bounds. However, such a thing is a logic error, and is
 	/// on the user of the struct to avoid.
 	pos: usize,
+	/// Positions that have been recorded under a name, using [`Self::set_checkpoint()`].
+	///
+	/// Unlike `pos`, these are not kept within the collection's bounds, and so must be clamped
+	/// when they are used. They are also not taken into account when comparing or hashing
+	/// cursors.
+	#[cfg(feature = "alloc")]
+	#[cfg_attr(feature = "serde", serde(skip))]
+	checkpoints: BTreeMap<&'static str, usize>,
+	/// Positions that have been saved with [`Self::push_position()`], from oldest to newest.
+	///
+	/// As with `checkpoints`, these are not kept within the collection's bounds, and are not
+	/// taken into account when comparing or hashing cursors.
+	#[cfg(feature = "alloc")]
+	#[cfg_attr(feature = "serde", serde(skip))]
+	position_stack: Vec<usize>,
 }
 
 impl<Tape> CollectionCursor<Tape> {
 	/// Creates a new `CollectionCursor` wrapping the provided collection.
 	///
 	/// The cursor's initial position will always be `0`.
-	pub fn new(inner: Tape) -> Self {
+	///
+	/// As this is a `const fn`, it can be used to create cursors in a const context, such as a
+	/// `static`.
+	pub const fn new(inner: Tape) -> Self {
 		Self {
 			inner,
-			pos: Default::default(),
+			pos: 0,
+			#[cfg(feature = "alloc")]
+			checkpoints: BTreeMap::new(),
+			#[cfg(feature = "alloc")]
+			position_stack: Vec::new(),
 		}
 	}
 
@@ -60,24 +118,88 @@ impl<Tape> CollectionCursor<Tape> {
 	pub fn into_inner(self) -> Tape {
 		self.inner
 	}
+
+	/// Returns the cursor to the index `0`. Nothing else is changed - the collection, as well as
+	/// any checkpoints recorded with [`Self::set_checkpoint()`] or positions saved with
+	/// [`Self::push_position()`], are left untouched.
+	///
+	/// Use [`Self::reset()`] instead to also clear the collection and forget any checkpoints or
+	/// saved positions.
+	pub fn reset_position(&mut self) {
+		self.pos = 0;
+	}
+
+	/// Records the current position of the cursor, so that it can later be returned to with
+	/// [`Self::restore()`], or compared against with [`Self::advanced_since()`].
+	pub fn snapshot(&self) -> CursorSnapshot {
+		CursorSnapshot { pos: self.pos }
+	}
+
+	/// Returns the number of positions the cursor has moved since `snapshot` was taken. This is
+	/// positive if the cursor has moved forward, and negative if it has moved backward.
+	///
+	/// If the distance does not fit within an `isize`, the result wraps around.
+	pub fn advanced_since(&self, snapshot: CursorSnapshot) -> isize {
+		self.pos.wrapping_sub(snapshot.pos).cast_signed()
+	}
+
+	/// Swaps this cursor with `other`, exchanging both their collections and their positions.
+	/// Neither collection is copied or reallocated.
+	///
+	/// Any checkpoints recorded with [`Self::set_checkpoint()`] and positions saved with
+	/// [`Self::push_position()`] are exchanged as well, so they stay with the collection they were
+	/// recorded for. As with the cursor's position, they are never applied to the other collection.
+	///
+	/// This is equivalent to `core::mem::swap(self, other)`, and is useful for double-buffering,
+	/// where one cursor is read from while the other is written to.
+	pub fn swap_with(&mut self, other: &mut Self) {
+		core::mem::swap(self, other);
+	}
+
+	/// Returns a value whose `Debug` output is the same as the cursor's own, except that the
+	/// position is shown without the collection's length, such as
+	/// `CollectionCursor { pos: 5, inner: [...] }`.
+	///
+	/// This is a fallback for collections which implement `Debug`, but not
+	/// [`IndexableCollection`], and so can't use the cursor's own `Debug` implementation.
+	pub fn debug_without_len(&self) -> impl fmt::Debug + '_
+	where
+		Tape: fmt::Debug,
+	{
+		DebugWithoutLen(self)
+	}
 }
 
 impl<Tape: IndexableCollection> CollectionCursor<Tape> {
+	/// Returns whether the cursor is at the start of the collection (index `0`).
+	pub fn is_cursor_at_start(&self) -> bool {
+		self.pos == 0
+	}
+
 	/// Returns whether the cursor is at the end of the collection (one index past the last item in
 	/// the collection).
 	pub fn is_cursor_at_end(&self) -> bool {
 		self.pos == self.inner.len()
 	}
 
-	/// Moves the cursor to a new index.
+	/// Returns the number of items from the cursor to the end of the collection.
+	///
+	/// If the cursor is past the end of the collection (such as after shrinking the collection
+	/// through [`Self::get_mut()`]), this returns `0`.
+	pub fn remaining(&self) -> usize {
+		self.inner.len().saturating_sub(self.pos)
+	}
+
+	/// Moves the cursor to a new index, returning the new position of the cursor.
 	///
-	/// It is an error to seek to a position before `0` or after `self.get_ref().len()`. In these
-	/// cases, `None` will be returned and the cursor will not be moved.
+	/// # Errors
+	/// Returns an [`OutOfBoundsError`] if the new position would be before `0` or after
+	/// `self.get_ref().len()`. In these cases, the cursor will not be moved.
 	///
-	/// Otherwise, this will return `Some(new_pos)`, where `new_pos` is the new position of the
-	/// cursor.
-	// TODO: Change to something like `Result<usize, OutOfBoundsError>`
-	pub fn seek(&mut self, pos: SeekFrom) -> Option<usize> {
+	/// # Panics
+	/// When the `strict-arithmetic` feature is enabled and debug assertions are on, this panics if
+	/// calculating the new position overflows past `usize::MAX`.
+	pub fn seek(&mut self, pos: SeekFrom) -> Result<usize, OutOfBoundsError> {
 		let collection_len = self.inner.len();
 
 		let desired_position = match pos {
@@ -86,9 +208,61 @@ impl<Tape: IndexableCollection> CollectionCursor<Tape> {
 			SeekFrom::Current(p) => self.pos.checked_add_signed(p),
 		};
 
-		desired_position
-			.filter(|&pos| pos <= collection_len)
-			.inspect(|&new_pos| self.pos = new_pos)
+		// Seeking before `0` is an ordinary out-of-bounds seek, which we don't want to panic on.
+		// Overflowing past `usize::MAX`, however, requires a pathological position or offset.
+		let desired_position = if matches!(pos, SeekFrom::End(p) | SeekFrom::Current(p) if p < 0) {
+			desired_position
+		} else {
+			strict_checked(
+				desired_position,
+				format_args!(
+					"calculating the position to seek to with `{pos:?}` overflowed `usize`"
+				),
+			)
+		};
+
+		match desired_position {
+			Some(new_pos) if new_pos <= collection_len => {
+				self.pos = new_pos;
+				Ok(new_pos)
+			}
+			Some(new_pos) => Err(OutOfBoundsError {
+				kind: OutOfBoundsKind::PastEnd,
+				attempted: Some(new_pos),
+				collection_len,
+			}),
+			None => {
+				let kind = match pos {
+					SeekFrom::End(p) | SeekFrom::Current(p) if p < 0 => {
+						OutOfBoundsKind::BeforeStart
+					}
+					_ => OutOfBoundsKind::Overflow,
+				};
+
+				Err(OutOfBoundsError {
+					kind,
+					attempted: None,
+					collection_len,
+				})
+			}
+		}
+	}
+
+	/// Moves the cursor to a new index, returning the new position of the cursor. Unlike
+	/// [`Self::seek()`], a position outside of the collection's bounds is not rejected. Instead,
+	/// the cursor is moved as far as it can go - to `0` for positions before the start of the
+	/// collection, or to `self.get_ref().len()` for positions after the end.
+	pub fn seek_clamped(&mut self, pos: SeekFrom) -> usize {
+		let collection_len = self.inner.len();
+
+		let desired_position = match pos {
+			SeekFrom::Start(p) => p,
+			SeekFrom::End(p) => collection_len.saturating_add_signed(p),
+			SeekFrom::Current(p) => self.pos.saturating_add_signed(p),
+		};
+
+		self.pos = desired_position.min(collection_len);
+		self.pos
 	}
 
 	/// Clamps the cursor to the index of the last item, or `0` if no items exist. If the cursor is
@@ -96,9 +270,7 @@ impl<Tape: IndexableCollection> CollectionCursor<Tape> {
 	pub fn clamp_to_last_item(&mut self) {
 		// `usize`, by its nature, cannot be below `0`. Thus, we only need to know which is the
 		// smaller value: the collection length, or the head position
-		self.pos = self
-			.pos
-			.min(self.inner.len().checked_sub(1).unwrap_or_default());
+		self.pos = self.pos.min(self.inner.len().saturating_sub(1));
 	}
 
 	/// Clamps the cursor to one index past the last item. If the cursor is before or at that index,
@@ -109,6 +281,19 @@ impl<Tape: IndexableCollection> CollectionCursor<Tape> {
 		self.pos = self.pos.min(self.inner.len());
 	}
 
+	/// Clamps the cursor to within `range`, as well as to within the collection's bounds (`0` up
+	/// to and including `self.get_ref().len()`). If the cursor is already within both, nothing will
+	/// happen.
+	///
+	/// If `range` ends past the end of the collection, it is treated as ending at the end of the
+	/// collection. If `range` is empty, or starts past the end of the collection, the cursor is
+	/// moved to the (clamped) end of `range`.
+	pub fn clamp_position_to(&mut self, range: RangeInclusive<usize>) {
+		let max = (*range.end()).min(self.inner.len());
+		let min = (*range.start()).min(max);
+		self.pos = self.pos.clamp(min, max);
+	}
+
 	/// Moves the cursor to the beginning of the collection.
 	///
 	/// This is a convenience method, equivalent to `self.seek(SeekFrom::Start(0))`.
@@ -120,32 +305,71 @@ impl<Tape: IndexableCollection> CollectionCursor<Tape> {
 	/// if we're already at the beginning of the collection.
 	///
 	/// This is a convenience method, equivalent to `self.seek(SeekFrom::Current(-1))`.
+	///
+	/// # Panics
+	/// When the `strict-arithmetic` feature is enabled and debug assertions are on, this panics if
+	/// calculating the new position overflows past `usize::MAX`.
 	pub fn seek_backward_one(&mut self) -> bool {
-		self.seek_relative(-1).is_some()
+		self.seek_relative(-1).is_ok()
 	}
 
 	/// Moves the cursor relative to the current position. The return value is the same as the one
 	/// returned for [`Self::seek()`].
 	///
 	/// This is a convenience method, equivalent to `self.seek(SeekFrom::Current(offset))`.
-	// TODO: Change to something like `Result<usize, OutOfBoundsError>`
-	pub fn seek_relative(&mut self, offset: isize) -> Option<usize> {
+	///
+	/// # Errors
+	/// Returns an [`OutOfBoundsError`] if the new position would be before `0` or after
+	/// `self.get_ref().len()`. In these cases, the cursor will not be moved.
+	///
+	/// # Panics
+	/// When the `strict-arithmetic` feature is enabled and debug assertions are on, this panics if
+	/// calculating the new position overflows past `usize::MAX`.
+	pub fn seek_relative(&mut self, offset: isize) -> Result<usize, OutOfBoundsError> {
 		self.seek(SeekFrom::Current(offset))
 	}
 
+	/// Moves the cursor relative to the current position, returning the new position.
+	///
+	/// # Errors
+	/// If the new position would be before `0` or after `self.get_ref().len()`, returns the number
+	/// of positions by which it would have exceeded those bounds. This is negative if the new
+	/// position would be before `0`, and positive if it would be after the end. In these cases,
+	/// the cursor will not be moved.
+	///
+	/// If the overshoot does not fit within an `isize`, it is saturated to `isize::MIN` or
+	/// `isize::MAX`.
+	///
+	/// # Panics
+	/// With the `strict-arithmetic` feature enabled, panics in debug builds under the same
+	/// circumstances as [`Self::seek()`].
+	pub fn seek_relative_reporting(&mut self, offset: isize) -> Result<usize, isize> {
+		self.seek_relative(offset).map_err(|_| {
+			if offset < 0 {
+				offset.saturating_add_unsigned(self.pos)
+			} else {
+				offset.saturating_sub_unsigned(self.inner.len().saturating_sub(self.pos))
+			}
+		})
+	}
+
 	/// Moves the cursor forwards one item, if an item exists. Returns `true` if the move was
 	/// successful, and `false` if we're already at the end of the collection.
 	///
 	/// This is a convenience method, equivalent to `self.seek(SeekFrom::Current(1))`.
+	///
+	/// # Panics
+	/// When the `strict-arithmetic` feature is enabled and debug assertions are on, this panics if
+	/// calculating the new position overflows past `usize::MAX`.
 	pub fn seek_forward_one(&mut self) -> bool {
-		self.seek_relative(1).is_some()
+		self.seek_relative(1).is_ok()
 	}
 
 	/// Moves the cursor to the index of the last item, or to `0` if no items exist.
 	///
 	/// This is a convenience method, equivalent to `self.seek(SeekFrom::End(-1))`.
 	pub fn seek_to_last_item(&mut self) {
-		self.pos = self.inner.len().checked_sub(1).unwrap_or_default();
+		self.pos = self.inner.len().saturating_sub(1);
 	}
 
 	/// Moves the cursor to one index past the last item.
@@ -155,12 +379,547 @@ impl<Tape: IndexableCollection> CollectionCursor<Tape> {
 		self.pos = self.inner.len();
 	}
 
+	/// Moves the cursor to the middle of the collection (index `self.get_ref().len() / 2`, rounded
+	/// down), and returns the new position of the cursor. If the collection is empty, this is `0`.
+	pub fn seek_to_middle(&mut self) -> usize {
+		self.pos = self.inner.len() / 2;
+		self.pos
+	}
+
 	/// Returns a reference to the element pointed at by the cursor.
 	///
 	/// Returns `None` if `self.position() >= self.get_ref().len()`.
 	pub fn get_item_at_cursor(&self) -> Option<&Tape::Item> {
 		self.inner.get_item(self.pos)
 	}
+
+	/// Returns an iterator over the items from the cursor to the end of the collection. The cursor
+	/// is not moved.
+	pub fn iter_from_cursor(&self) -> IterFromCursor<'_, Tape> {
+		IterFromCursor::new(&self.inner, self.pos)
+	}
+
+	/// Returns an iterator over the items from the cursor to the end of the collection, in reverse
+	/// order (from the last item back to the item at the cursor). The cursor is not moved.
+	///
+	/// This is equivalent to `self.iter_from_cursor().rev()`.
+	pub fn iter_from_cursor_rev(&self) -> Rev<IterFromCursor<'_, Tape>> {
+		self.iter_from_cursor().rev()
+	}
+
+	/// Moves the cursor backwards to the start of the current line - that is, to just after the
+	/// closest newline before the cursor, or to `0` if no newline exists before the cursor. Returns
+	/// the new position of the cursor.
+	///
+	/// Which items are newlines is decided by `is_newline`. If the item just before the cursor is a
+	/// newline, the cursor is already at the start of a line, and will not be moved.
+	pub fn seek_to_start_of_line<F: FnMut(&Tape::Item) -> bool>(
+		&mut self,
+		mut is_newline: F,
+	) -> usize {
+		let search_end = self.pos.min(self.inner.len());
+
+		self.pos = (0..search_end)
+			.rev()
+			.find(|&index| self.inner.get_item(index).is_some_and(&mut is_newline))
+			.map_or(0, |newline_index| newline_index + 1);
+		self.pos
+	}
+
+	/// Moves the cursor forwards to the end of the current line - that is, onto the closest newline
+	/// at or after the cursor, or to `self.get_ref().len()` if no newline exists after the cursor.
+	/// Returns the new position of the cursor.
+	///
+	/// Which items are newlines is decided by `is_newline`. If the item at the cursor is a newline,
+	/// the cursor is already at the end of a line, and will not be moved.
+	pub fn seek_to_end_of_line<F: FnMut(&Tape::Item) -> bool>(
+		&mut self,
+		mut is_newline: F,
+	) -> usize {
+		let collection_len = self.inner.len();
+
+		self.pos = (self.pos..collection_len)
+			.find(|&index| self.inner.get_item(index).is_some_and(&mut is_newline))
+			.unwrap_or(collection_len);
+		self.pos
+	}
+
+	/// Returns the number of items from the cursor up to and including the first item for which
+	/// `is_terminator` returns `true`, or `None` if no such item exists at or after the cursor. The
+	/// cursor is not moved.
+	///
+	/// This is useful for finding the full length of a terminator-delimited frame which begins at
+	/// the cursor.
+	pub fn frame_length_from_cursor<F: FnMut(&Tape::Item) -> bool>(
+		&self,
+		is_terminator: F,
+	) -> Option<usize> {
+		self.iter_from_cursor()
+			.position(is_terminator)
+			.map(|terminator_offset| terminator_offset + 1)
+	}
+
+	/// If the items starting at the cursor are equal to `pattern`, moves the cursor just past them
+	/// and returns `true`. Otherwise, the cursor is not moved and `false` is returned.
+	///
+	/// Only a match starting exactly at the cursor is considered - occurrences of `pattern` further
+	/// along the collection are ignored.
+	pub fn consume_subsequence(&mut self, pattern: &[Tape::Item]) -> bool
+	where
+		Tape::Item: PartialEq,
+	{
+		let mut items = self.iter_from_cursor();
+		let is_match = pattern
+			.iter()
+			.all(|expected| items.next() == Some(expected));
+
+		if is_match {
+			self.pos += pattern.len();
+		}
+		is_match
+	}
+
+	/// Moves the cursor forward `n` items, stopping at `self.get_ref().len()` if that would move it
+	/// past the end of the collection. Returns the new position of the cursor.
+	///
+	/// Unlike [`Self::seek_relative()`], this never fails.
+	pub fn move_forward(&mut self, n: usize) -> usize {
+		self.pos = self.pos.saturating_add(n).min(self.inner.len());
+		self.pos
+	}
+
+	/// Moves the cursor backward `n` items, stopping at `0` if that would move it past the start of
+	/// the collection. Returns the new position of the cursor.
+	///
+	/// Unlike [`Self::seek_relative()`], this never fails.
+	pub fn move_backward(&mut self, n: usize) -> usize {
+		self.pos = self.pos.saturating_sub(n).min(self.inner.len());
+		self.pos
+	}
+
+	/// Returns whether the items from the cursor to the end of the collection are equal to
+	/// `other`. That is, whether both have the same length, and contain equal items in the same
+	/// order.
+	pub fn remaining_eq(&self, other: &[Tape::Item]) -> bool
+	where
+		Tape::Item: PartialEq,
+	{
+		self.iter_from_cursor().eq(other)
+	}
+
+	/// Moves the cursor onto the largest item at or after the cursor, and returns its index. If
+	/// several items are equally large, the last of them is chosen.
+	///
+	/// Returns `None` if no items exist at or after the cursor. In this case, the cursor will not
+	/// be moved.
+	pub fn seek_to_max(&mut self) -> Option<usize>
+	where
+		Tape::Item: Ord,
+	{
+		let (offset, _) = self
+			.iter_from_cursor()
+			.enumerate()
+			.max_by_key(|&(_, item)| item)?;
+
+		self.pos += offset;
+		Some(self.pos)
+	}
+
+	/// Moves the cursor onto the smallest item at or after the cursor, and returns its index. If
+	/// several items are equally small, the first of them is chosen.
+	///
+	/// Returns `None` if no items exist at or after the cursor. In this case, the cursor will not
+	/// be moved.
+	pub fn seek_to_min(&mut self) -> Option<usize>
+	where
+		Tape::Item: Ord,
+	{
+		let (offset, _) = self
+			.iter_from_cursor()
+			.enumerate()
+			.min_by_key(|&(_, item)| item)?;
+
+		self.pos += offset;
+		Some(self.pos)
+	}
+
+	/// Binary searches the whole collection for `target`, moves the cursor to the resulting index,
+	/// and returns the same result as [`slice::binary_search`]. The cursor ends up at the returned
+	/// index in both cases.
+	///
+	/// If `target` is found, `Ok` is returned with its index. If several items are equal to
+	/// `target`, any of them may be chosen. If `target` is not found, `Err` is returned with the
+	/// index where it could be inserted while keeping the collection sorted.
+	///
+	/// The collection must be sorted in ascending order. If it is not, the result (and the
+	/// cursor's new position) is unspecified, but will still be within the collection's bounds.
+	///
+	/// # Errors
+	/// Returns `Err` with the insertion index if `target` was not found.
+	pub fn binary_search_seek(&mut self, target: &Tape::Item) -> Result<usize, usize>
+	where
+		Tape::Item: Ord,
+	{
+		let mut low = 0;
+		let mut high = self.inner.len();
+
+		let res = loop {
+			if low >= high {
+				break Err(low);
+			}
+
+			let mid = low + (high - low) / 2;
+			match self.inner.get_item(mid).map(|item| item.cmp(target)) {
+				Some(Ordering::Less) => low = mid + 1,
+				Some(Ordering::Greater) | None => high = mid,
+				Some(Ordering::Equal) => break Ok(mid),
+			}
+		};
+
+		let (Ok(index) | Err(index)) = res;
+		self.pos = index;
+		res
+	}
+
+	/// Returns whether this cursor is equal to `other`, even if the two are backed by different
+	/// types of collection. That is, whether both cursors are at the same position, and both
+	/// collections have the same length and contain equal items in the same order.
+	///
+	/// This is useful for comparing, for example, a cursor over a `Vec` with a cursor over a
+	/// `VecDeque`, which the `PartialEq` implementation does not allow.
+	pub fn content_eq_with<Other: IndexableCollection<Item = Tape::Item>>(
+		&self,
+		other: &CollectionCursor<Other>,
+	) -> bool
+	where
+		Tape::Item: PartialEq,
+	{
+		let len = self.inner.len();
+
+		self.pos == other.pos
+			&& len == other.inner.len()
+			&& (0..len).all(|index| self.inner.get_item(index) == other.inner.get_item(index))
+	}
+
+	/// Returns an iterator over up to `k` items, starting at the cursor. Fewer than `k` items will
+	/// be returned if the end of the collection is reached first. The cursor is not moved.
+	///
+	/// This is equivalent to `self.iter_from_cursor().take(k)`.
+	pub fn peek_ahead_iter(&self, k: usize) -> impl Iterator<Item = &Tape::Item> + '_ {
+		self.iter_from_cursor().take(k)
+	}
+
+	/// Returns whether `0 <= self.position() <= self.get_ref().len()` is upheld.
+	///
+	/// This will only return `false` if the collection was shrunk through [`Self::get_mut()`]
+	/// without clamping the cursor afterwards, which is a logic error. As such, this is mostly
+	/// useful for debugging and assertions.
+	pub fn position_is_valid(&self) -> bool {
+		self.pos <= self.inner.len()
+	}
+
+	/// Returns an iterator over the indices of every item at or after the cursor for which `pred`
+	/// returns `true`, in ascending order. The cursor is not moved.
+	pub fn match_positions<F: FnMut(&Tape::Item) -> bool>(
+		&self,
+		mut pred: F,
+	) -> impl Iterator<Item = usize> {
+		let start = self.pos;
+
+		self.iter_from_cursor()
+			.enumerate()
+			.filter_map(move |(offset, item)| pred(item).then_some(start + offset))
+	}
+
+	/// Returns whether the items starting at `index` are equal to the items of `expected`, in
+	/// order. The cursor is not used or moved.
+	///
+	/// Returns `false` if the collection would end before all of `expected` could be compared.
+	pub fn items_equal_at(&self, index: usize, expected: &[Tape::Item]) -> bool
+	where
+		Tape::Item: PartialEq,
+	{
+		let fits = index
+			.checked_add(expected.len())
+			.is_some_and(|end| end <= self.inner.len());
+
+		fits && expected
+			.iter()
+			.enumerate()
+			.all(|(offset, item)| self.inner.get_item(index + offset) == Some(item))
+	}
+
+	/// Moves the cursor by `records` records, where each record is `width` items long. A negative
+	/// `records` moves the cursor backward. The return value is the same as the one returned for
+	/// [`Self::seek()`].
+	///
+	/// If `width` is `0`, the cursor is not moved, and the current position is returned. However,
+	/// if the cursor is already past the end of the collection, an error is returned instead, as
+	/// with `self.seek(SeekFrom::Current(0))`.
+	///
+	/// # Errors
+	/// Returns an [`OutOfBoundsError`] if the new position would be before `0` or after
+	/// `self.get_ref().len()`, or if calculating the offset overflows `isize`. In these cases, the
+	/// cursor will not be moved.
+	///
+	/// # Panics
+	/// When the `strict-arithmetic` feature is enabled and debug assertions are on, this panics if
+	/// calculating the offset overflows `isize`, or if calculating the new position overflows past
+	/// `usize::MAX`.
+	pub fn seek_records(
+		&mut self,
+		records: isize,
+		width: usize,
+	) -> Result<usize, OutOfBoundsError> {
+		let offset = strict_checked(
+			isize::try_from(width)
+				.ok()
+				.and_then(|width| width.checked_mul(records)),
+			format_args!(
+				"calculating the offset of {records} records of width {width} overflowed `isize`"
+			),
+		);
+
+		match offset {
+			Some(offset) => self.seek_relative(offset),
+			None => Err(OutOfBoundsError {
+				kind: OutOfBoundsKind::Overflow,
+				attempted: None,
+				collection_len: self.inner.len(),
+			}),
+		}
+	}
+
+	/// Moves the cursor forward past each consecutive item for which `pred` returns `true`,
+	/// folding each of those items into an accumulator with `fold`. Returns the final
+	/// accumulator, or `init` if no items were passed.
+	///
+	/// Afterwards, the cursor points at the first item for which `pred` returned `false`, or at
+	/// the end of the collection if there is no such item.
+	pub fn advance_while_fold<B, F, P>(&mut self, init: B, mut pred: P, mut fold: F) -> B
+	where
+		P: FnMut(&Tape::Item) -> bool,
+		F: FnMut(B, &Tape::Item) -> B,
+	{
+		let mut acc = init;
+		while let Some(item) = self.inner.get_item(self.pos)
+			&& pred(item)
+		{
+			acc = fold(acc, item);
+			self.pos += 1;
+		}
+
+		acc
+	}
+
+	/// Moves the cursor back to the position recorded in `snapshot` by [`Self::snapshot()`].
+	/// Returns the new position of the cursor.
+	///
+	/// If the collection has shrunk since the snapshot was taken, the cursor is clamped to
+	/// `self.get_ref().len()`.
+	pub fn restore(&mut self, snapshot: CursorSnapshot) -> usize {
+		self.pos = snapshot.pos.min(self.inner.len());
+		self.pos
+	}
+
+	/// Moves the cursor back to the position recorded in `snapshot` by [`Self::snapshot()`], and
+	/// returns `true`.
+	///
+	/// If the collection has shrunk since the snapshot was taken, such that the recorded position
+	/// is now past the end of the collection, the snapshot is stale. In this case, `false` is
+	/// returned, and the cursor is not moved. Use [`Self::restore()`] to clamp instead.
+	pub fn try_restore(&mut self, snapshot: CursorSnapshot) -> bool {
+		let is_valid = snapshot.pos <= self.inner.len();
+
+		if is_valid {
+			self.pos = snapshot.pos;
+		}
+		is_valid
+	}
+
+	/// Calls `f` with this cursor, and moves the cursor back to where it was beforehand if `f`
+	/// returns an error. If `f` returns `Ok`, the cursor is left wherever `f` moved it. This is
+	/// useful for trying to parse something, and backtracking if that fails.
+	///
+	/// If the collection has shrunk while `f` was running, the cursor is clamped to
+	/// `self.get_ref().len()`, as with [`Self::restore()`].
+	///
+	/// # Errors
+	/// Returns the error returned by `f`, if any.
+	pub fn with_bookmark<R, E, F: FnOnce(&mut Self) -> Result<R, E>>(
+		&mut self,
+		f: F,
+	) -> Result<R, E> {
+		let snapshot = self.snapshot();
+		let res = f(self);
+
+		if res.is_err() {
+			self.restore(snapshot);
+		}
+		res
+	}
+
+	/// Returns a clone of the item at the cursor, or the result of calling `f` if the cursor is
+	/// out-of-bounds. `f` is only called if it is needed.
+	pub fn get_item_at_cursor_or_else<F: FnOnce() -> Tape::Item>(&self, f: F) -> Tape::Item
+	where
+		Tape::Item: Clone,
+	{
+		self.get_item_at_cursor().cloned().unwrap_or_else(f)
+	}
+
+	/// Returns the index of the nearest item before the cursor for which `pred` returns `true`,
+	/// searching backward from the cursor. The item at the cursor itself is not considered, and
+	/// the cursor is not moved.
+	///
+	/// Returns `None` if no item before the cursor matches.
+	pub fn position_of_prev_match<F: FnMut(&Tape::Item) -> bool>(
+		&self,
+		mut pred: F,
+	) -> Option<usize> {
+		(0..self.pos.min(self.inner.len()))
+			.rev()
+			.find(|&index| self.inner.get_item(index).is_some_and(&mut pred))
+	}
+
+	/// Returns an iterator which endlessly repeats the items from the cursor to the end of the
+	/// collection, like [`Iterator::cycle`]. The cursor is not moved.
+	///
+	/// If there are no items at or after the cursor, the iterator is empty.
+	pub fn cycle_from_cursor(&self) -> impl Iterator<Item = &Tape::Item> + '_ {
+		self.iter_from_cursor().cycle()
+	}
+
+	/// Lexicographically compares the items from the cursor to the end of the collection with the
+	/// items from `other`'s cursor to the end of its collection.
+	///
+	/// Unlike the `Ord` implementation, which compares the entire collections and then the
+	/// positions, this only considers the items that have yet to be reached by each cursor.
+	pub fn cmp_remaining(&self, other: &Self) -> Ordering
+	where
+		Tape::Item: Ord,
+	{
+		self.iter_from_cursor().cmp(other.iter_from_cursor())
+	}
+
+	/// Returns a reference to the item at the cursor, and moves the cursor forward past it.
+	///
+	/// Returns `None` if the cursor is out-of-bounds. In this case, the cursor will not be moved.
+	pub fn read_and_advance(&mut self) -> Option<&Tape::Item> {
+		let item = self.inner.get_item(self.pos)?;
+		self.pos += 1;
+		Some(item)
+	}
+
+	/// Returns a reference to the item `n` items after the cursor, without moving the cursor.
+	/// `self.peek_ahead(0)` is equivalent to [`Self::get_item_at_cursor()`].
+	///
+	/// Returns `None` if no item exists there.
+	pub fn peek_ahead(&self, n: usize) -> Option<&Tape::Item> {
+		self.inner.get_item(self.pos.checked_add(n)?)
+	}
+
+	/// Returns a reference to the item `n` items before the cursor, without moving the cursor.
+	/// `self.peek_behind(0)` is equivalent to [`Self::get_item_at_cursor()`].
+	///
+	/// Returns `None` if no item exists there.
+	pub fn peek_behind(&self, n: usize) -> Option<&Tape::Item> {
+		self.inner.get_item(self.pos.checked_sub(n)?)
+	}
+
+	/// Returns a reference to the item just before the cursor - that is, the item most recently
+	/// moved past.
+	///
+	/// Returns `None` if the cursor is at the start of the collection, or if no item exists there.
+	pub fn get_item_before_cursor(&self) -> Option<&Tape::Item> {
+		self.inner.get_item(self.pos.checked_sub(1)?)
+	}
+
+	/// Moves the cursor onto the first item at or after the cursor for which `pred` returns
+	/// `true`, and returns `true`.
+	///
+	/// If no such item exists, the cursor is moved to the end of the collection, and `false` is
+	/// returned. This also moves a cursor which was past the end of the collection back to the end.
+	pub fn advance_to<F: FnMut(&Tape::Item) -> bool>(&mut self, pred: F) -> bool {
+		match self.iter_from_cursor().position(pred) {
+			Some(offset) => {
+				self.pos += offset;
+				true
+			}
+			None => {
+				self.pos = self.inner.len();
+				false
+			}
+		}
+	}
+
+	/// Returns the index of the first item at or after the cursor for which `pred` returns `true`.
+	/// The cursor is not moved.
+	///
+	/// The returned index is absolute, rather than relative to the cursor, so it can be passed
+	/// directly to `SeekFrom::Start`. Returns `None` if no item at or after the cursor matches.
+	pub fn find_from_cursor<F: FnMut(&Tape::Item) -> bool>(&self, pred: F) -> Option<usize> {
+		let offset = self.iter_from_cursor().position(pred)?;
+		Some(self.pos + offset)
+	}
+
+	/// Moves the cursor onto the first item at or after the cursor for which `pred` returns `true`,
+	/// and returns the cursor's new position.
+	///
+	/// If no such item exists, `None` is returned, and the cursor is not moved. Use
+	/// [`Self::advance_to()`] instead to move to the end of the collection in that case.
+	pub fn seek_to_next<F: FnMut(&Tape::Item) -> bool>(&mut self, pred: F) -> Option<usize> {
+		self.pos = self.find_from_cursor(pred)?;
+		Some(self.pos)
+	}
+
+	/// Moves the cursor onto the nearest item before the cursor for which `pred` returns `true`,
+	/// and returns the cursor's new position. The item at the cursor itself is not considered.
+	///
+	/// If no such item exists, `None` is returned, and the cursor is not moved.
+	pub fn seek_to_prev<F: FnMut(&Tape::Item) -> bool>(&mut self, pred: F) -> Option<usize> {
+		self.pos = self.position_of_prev_match(pred)?;
+		Some(self.pos)
+	}
+
+	/// Moves the cursor onto the first item after the cursor for which `is_header` returns `true`,
+	/// and returns the cursor's new position. The item at the cursor is skipped, even if it is a
+	/// header, so that repeated calls move from one record to the next.
+	///
+	/// If no such item exists, `None` is returned, and the cursor is not moved.
+	pub fn seek_to_next_record<F: FnMut(&Tape::Item) -> bool>(
+		&mut self,
+		is_header: F,
+	) -> Option<usize> {
+		let start = self.pos.checked_add(1)?;
+		let offset = IterFromCursor::new(&self.inner, start).position(is_header)?;
+
+		self.pos = start + offset;
+		Some(self.pos)
+	}
+
+	/// Returns references to the item before the cursor, the item at the cursor, and the item
+	/// after the cursor, in that order. Each is `None` if no item exists there.
+	///
+	/// This is equivalent to `(self.peek_behind(1), self.get_item_at_cursor(),
+	/// self.peek_ahead(1))`.
+	#[expect(
+		clippy::type_complexity,
+		reason = "splitting this into a type alias would only obscure it"
+	)]
+	pub fn cursor_neighborhood(
+		&self,
+	) -> (
+		Option<&Tape::Item>,
+		Option<&Tape::Item>,
+		Option<&Tape::Item>,
+	) {
+		(
+			self.peek_behind(1),
+			self.get_item_at_cursor(),
+			self.peek_ahead(1),
+		)
+	}
 }
 
 impl<Tape: IndexableCollectionMut> CollectionCursor<Tape> {
@@ -171,6 +930,13 @@ impl<Tape: IndexableCollectionMut> CollectionCursor<Tape> {
 		self.inner.get_item_mut(self.pos)
 	}
 
+	/// Returns a mutable reference to the item just before the cursor.
+	///
+	/// Returns `None` if the cursor is at the start of the collection, or if no item exists there.
+	pub fn get_item_before_cursor_mut(&mut self) -> Option<&mut Tape::Item> {
+		self.inner.get_item_mut(self.pos.checked_sub(1)?)
+	}
+
 	/// Sets the slot at the cursor to `item`.
 	///
 	/// # Panics
@@ -179,6 +945,68 @@ impl<Tape: IndexableCollectionMut> CollectionCursor<Tape> {
 	pub fn set_item_at_cursor(&mut self, item: Tape::Item) {
 		self.inner.set_item(self.pos, item);
 	}
+
+	/// Returns an [`EditGuard`], through which the collection can be modified without the cursor
+	/// ever being left out-of-bounds. The cursor is clamped when the guard is created, and again
+	/// after every modification made through it.
+	pub fn edit(&mut self) -> EditGuard<'_, Tape> {
+		EditGuard::new(self)
+	}
+
+	/// Reserves capacity for at least `additional` more items to be inserted into the collection,
+	/// such as before inserting many items at the cursor.
+	///
+	/// This calls [`IndexableCollectionMut::reserve()`], so collections that can't grow may ignore
+	/// it.
+	pub fn reserve(&mut self, additional: usize) {
+		self.inner.reserve(additional);
+	}
+
+	/// Sets every item from the cursor to the end of the collection to `Default::default()`. Items
+	/// before the cursor are left untouched, and neither the cursor nor the length of the
+	/// collection are changed.
+	pub fn clear_tail_to_default(&mut self)
+	where
+		Tape::Item: Default,
+	{
+		for index in self.pos..self.inner.len() {
+			if let Some(item) = self.inner.get_item_mut(index) {
+				*item = Default::default();
+			}
+		}
+	}
+
+	/// Overwrites the items starting at the cursor with clones of the items in `src`. The cursor is
+	/// not moved, and the collection is never resized.
+	///
+	/// Returns `Ok(src.len())` if all of `src` was written.
+	///
+	/// # Errors
+	/// Returns `Err(written)` if the end of the collection was reached before all of `src` could
+	/// be written, where `written` is the number of items that were written. The items that fit
+	/// are still written.
+	pub fn try_overwrite_from_cursor(&mut self, src: &[Tape::Item]) -> Result<usize, usize>
+	where
+		Tape::Item: Clone,
+	{
+		let mut written = 0;
+		for item in src {
+			let Some(slot) = self.inner.get_item_mut(self.pos.saturating_add(written)) else {
+				return Err(written);
+			};
+			slot.clone_from(item);
+			written += 1;
+		}
+
+		Ok(written)
+	}
+
+	/// Calls `f` with a mutable reference to the item at the cursor, and returns the result.
+	///
+	/// Returns `None` without calling `f` if the cursor is out-of-bounds.
+	pub fn modify_item_at_cursor<R, F: FnOnce(&mut Tape::Item) -> R>(&mut self, f: F) -> Option<R> {
+		self.get_item_at_cursor_mut().map(f)
+	}
 }
 
 impl<Tape: IndexableCollectionResizable> CollectionCursor<Tape> {
@@ -188,6 +1016,23 @@ impl<Tape: IndexableCollectionResizable> CollectionCursor<Tape> {
 		self.pos = 0;
 	}
 
+	/// Removes all elements within the inner collection, returns the cursor to the index `0`, and
+	/// forgets any checkpoints recorded with [`Self::set_checkpoint()`] and any positions saved
+	/// with [`Self::push_position()`].
+	///
+	/// Afterwards, the cursor is in the same state as if it had just been created with
+	/// [`Self::new()`] from an empty collection. This makes it suitable for reusing a cursor,
+	/// without giving up the allocation held by the collection (if any).
+	pub fn reset(&mut self) {
+		self.inner.clear();
+		self.pos = 0;
+		#[cfg(feature = "alloc")]
+		{
+			self.checkpoints.clear();
+			self.position_stack.clear();
+		}
+	}
+
 	/// Inserts `item` at the cursor, shifting the following elements to the right by one index.
 	///
 	/// # Panics
@@ -225,30 +1070,337 @@ impl<Tape: IndexableCollectionResizable> CollectionCursor<Tape> {
 		// cursor to be. Meanwhile, if it's past the end, no item will be removed.
 		self.inner.remove_item(self.pos)
 	}
-}
 
-#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub enum SeekFrom {
-	/// Moves the cursor to the provided index.
+	/// Removes and returns the item at the cursor, but only if `pred` returns `true` for it. The
+	/// cursor is not moved.
 	///
-	/// # Examples
-	/// * `SeekFrom::Start(0)` will move the cursor to the first item
-	/// * `SeekFrom::Start(5)` will move the cursor to the sixth item
-	Start(usize),
-	/// Moves the cursor to the cassette's length (as provided by [`IndexableCollection::len`]) plus
-	/// the provided number of indices.
+	/// Returns `None` if `pred` returns `false`, or if `self.position() >= self.get_ref().len()`.
+	/// In these cases, the collection is left untouched.
+	pub fn remove_item_at_cursor_if<F: FnOnce(&Tape::Item) -> bool>(
+		&mut self,
+		pred: F,
+	) -> Option<Tape::Item> {
+		if self.get_item_at_cursor().is_some_and(pred) {
+			self.remove_item_at_cursor()
+		} else {
+			None
+		}
+	}
+
+	/// Returns an iterator which removes and yields every item from the cursor to the end of the
+	/// collection, in order. Afterwards, the collection only contains the items before the
+	/// cursor, and the cursor is at its end.
 	///
-	/// It is an error to seek before the first index, or more than one index past the last item.
+	/// As with `Vec::drain()`, the items are removed even if the iterator is dropped before being
+	/// fully consumed. Each item is removed with [`IndexableCollectionResizable::remove_item()`],
+	/// so for a `Vec`, `drain_from_cursor()` will be faster.
+	pub fn drain_items_from_cursor(&mut self) -> DrainItemsFromCursor<'_, Tape> {
+		DrainItemsFromCursor::new(self)
+	}
+
+	/// Removes the item at index `index`, moving the last item of the collection into its place,
+	/// and returns the removed item. Returns `None` if no item exists at index `index`, in which
+	/// case neither the collection nor the cursor are modified.
 	///
-	/// # Examples
-	/// * `SeekFrom::End(-1)` will move the cursor to the last item, if one exists
-	/// * `SeekFrom::End(0)` will move the cursor to the index just after the last item
-	End(isize),
-	/// Moves the cursor to the current position (as provided by [`CollectionCursor::position`])
-	/// plus the provided number of indices.
+	/// As only the last item is moved, the cursor is adjusted as follows:
 	///
-	/// It is an error to seek before the first index, or more than one index past the last item.
+	/// * If the cursor was on the last item, and a different item was removed, the cursor is moved
+	///   to `index`, so that it keeps pointing at the same item.
+	/// * If the cursor was at the end of the collection, it is moved back by one, so that it stays
+	///   at the end.
+	/// * Otherwise, the cursor is not moved. If the cursor was on the removed item, it now points
+	///   at the item that was moved into its place, or at the end of the collection if the last
+	///   item was removed.
+	pub fn swap_remove_item_at(&mut self, index: usize) -> Option<Tape::Item> {
+		let last_index = self.inner.len().checked_sub(1)?;
+		let item = self.inner.swap_remove_item(index)?;
+
+		if self.pos == last_index + 1 {
+			self.pos = last_index;
+		} else if self.pos == last_index && index < last_index {
+			self.pos = index;
+		}
+
+		Some(item)
+	}
+
+	/// Removes the item at the cursor, moving the last item of the collection into its place, and
+	/// returns the removed item. The cursor is not moved, and so will point at the item that was
+	/// moved into its place, or at the end of the collection if the last item was removed.
+	///
+	/// Returns `None` if `self.position() >= self.get_ref().len()`.
+	pub fn swap_remove_item_at_cursor(&mut self) -> Option<Tape::Item> {
+		self.swap_remove_item_at(self.pos)
+	}
+
+	/// Returns a mutable reference to the item at the cursor. If the cursor is at the end of the
+	/// collection, a default item is inserted at the cursor first.
+	///
+	/// # Panics
+	/// Panics if `self.position() > self.get_ref().len()`, or if the insert operation panics. The
+	/// circumstances for the latter are defined by the inner collection.
+	pub fn get_item_at_cursor_or_insert_default(&mut self) -> &mut Tape::Item
+	where
+		Tape::Item: Default,
+	{
+		if self.is_cursor_at_end() {
+			self.insert_item_at_cursor(Default::default());
+		}
+
+		self.inner
+			.get_item_mut(self.pos)
+			.expect("the cursor should not be past the end of the collection")
+	}
+
+	/// Inserts clones of the items in `src` at the start of the collection, in order, shifting
+	/// all other items to the right. The cursor is moved forward by `src.len()`, so that it keeps
+	/// pointing at the same item.
+	///
+	/// The items are inserted in bulk through [`IndexableCollectionResizable::insert_items()`], so
+	/// collections which support it only shift their existing items once.
+	///
+	/// # Panics
+	/// Panics if the insert operation panics. The circumstances for a panic are defined by the
+	/// inner collection.
+	///
+	/// Also panics if moving the cursor forward would overflow past `usize::MAX`, which can only
+	/// happen if the cursor was already past the end of the collection.
+	pub fn prepend_slice(&mut self, src: &[Tape::Item])
+	where
+		Tape::Item: Clone,
+	{
+		let inserted = self.inner.insert_items(0, src.iter().cloned());
+		self.pos = self
+			.pos
+			.checked_add(inserted)
+			.expect("the cursor's position should not overflow");
+	}
+
+	/// Inserts the items of `iter` at the cursor, in order, and moves the cursor just past the
+	/// inserted items. Items at or after the cursor are shifted to the right to make room.
+	///
+	/// If the cursor is at the end of the collection, this behaves like extending the collection.
+	/// The items are inserted with [`IndexableCollectionResizable::insert_items()`], so for a `Vec`,
+	/// the items after the cursor are only shifted once.
+	///
+	/// # Panics
+	/// Panics if the insert operation panics. The circumstances for a panic are defined by the
+	/// inner collection, but will usually occur if `self.position() > self.get_ref().len()`.
+	pub fn append_from<I: IntoIterator<Item = Tape::Item>>(&mut self, iter: I) {
+		self.pos += self.inner.insert_items(self.pos, iter);
+	}
+}
+
+impl<Tape: SplittableCollection> CollectionCursor<Tape> {
+	/// Consumes this cursor, splitting the collection at the cursor into two new cursors. The
+	/// first holds the items before the cursor, and the second holds the items from the cursor to
+	/// the end of the collection. Both cursors start at index `0`.
+	///
+	/// If the cursor is past the end of the collection, it is treated as being at the end. Any
+	/// checkpoints or saved positions are discarded.
+	pub fn split_at_cursor(mut self) -> (Self, Self) {
+		let at = self.pos.min(self.inner.len());
+		let after = self.inner.split_off(at);
+
+		(Self::new(self.inner), Self::new(after))
+	}
+}
+
+impl<Tape: ContiguousCollection> CollectionCursor<Tape> {
+	/// Returns a slice of the items from the cursor to the end of the collection.
+	///
+	/// If the cursor is at or past the end of the collection, the slice will be empty.
+	pub fn remaining_slice(&self) -> &[Tape::Item] {
+		self.inner.as_slice().get(self.pos..).unwrap_or_default()
+	}
+
+	/// Returns the items before the cursor, and the items from the cursor to the end of the
+	/// collection, as two slices. Neither the cursor nor the collection is modified.
+	///
+	/// If the cursor is past the end of the collection, it is treated as being at the end.
+	pub fn slices_around_cursor(&self) -> (&[Tape::Item], &[Tape::Item]) {
+		let slice = self.inner.as_slice();
+		slice.split_at(self.pos.min(slice.len()))
+	}
+
+	/// Returns an iterator over the ranges of indices covered by each run of consecutive equal
+	/// items, from the cursor to the end of the collection. Each run is as long as possible, and
+	/// the cursor is not moved.
+	pub fn runs_from_cursor(&self) -> impl Iterator<Item = Range<usize>> + '_
+	where
+		Tape::Item: PartialEq,
+	{
+		self.remaining_slice()
+			.chunk_by(|a, b| a == b)
+			.scan(self.pos, |start, run| {
+				let range = *start..*start + run.len();
+				*start = range.end;
+				Some(range)
+			})
+	}
+
+	/// Returns an iterator over growing slices of the items starting at the cursor. The first
+	/// slice contains only the item at the cursor, and each following slice contains one more
+	/// item, up to the slice returned by [`Self::remaining_slice()`]. The cursor is not moved.
+	///
+	/// If there are no items at or after the cursor, the iterator is empty.
+	pub fn prefixes_from_cursor(&self) -> impl Iterator<Item = &[Tape::Item]> + '_ {
+		let remaining = self.remaining_slice();
+		(1..=remaining.len()).map(move |len| &remaining[..len])
+	}
+
+	/// Returns an array containing copies of the next `N` items, starting at the cursor. The
+	/// cursor is not moved.
+	///
+	/// Returns `None` if fewer than `N` items exist at or after the cursor. See
+	/// [`Self::read_array_padded()`] for a version that pads the array instead.
+	pub fn read_array_from_cursor<const N: usize>(&self) -> Option<[Tape::Item; N]>
+	where
+		Tape::Item: Copy,
+	{
+		self.remaining_slice().first_chunk::<N>().copied()
+	}
+
+	/// Returns an array containing copies of the next `N` items, starting at the cursor. The
+	/// cursor is not moved.
+	///
+	/// If fewer than `N` items exist at or after the cursor, the rest of the array is filled with
+	/// `Default::default()`.
+	pub fn read_array_padded<const N: usize>(&self) -> [Tape::Item; N]
+	where
+		Tape::Item: Copy + Default,
+	{
+		let remaining = self.remaining_slice();
+		let read_len = remaining.len().min(N);
+
+		let mut array = [Default::default(); N];
+		array[..read_len].copy_from_slice(&remaining[..read_len]);
+		array
+	}
+}
+
+impl<Tape: ContiguousCollectionMut> CollectionCursor<Tape> {
+	/// Calls `f` on each consecutive chunk of `W` items, starting at the cursor. The cursor is not
+	/// moved.
+	///
+	/// The chunks never overlap, as overlapping mutable chunks would alias each other. If the
+	/// number of items from the cursor to the end of the collection isn't a multiple of `W`, the
+	/// leftover items at the end are skipped.
+	///
+	/// # Panics
+	/// Panics if `W` is `0`.
+	pub fn for_each_window_mut<const W: usize>(&mut self, f: impl FnMut(&mut [Tape::Item; W])) {
+		let Some(remaining) = self.inner.as_mut_slice().get_mut(self.pos..) else {
+			return;
+		};
+
+		let (windows, _leftover) = remaining.as_chunks_mut::<W>();
+		windows.iter_mut().for_each(f);
+	}
+}
+
+// `PartialEq`, `Eq`, `PartialOrd`, `Ord` and `Hash` are implemented manually, so that the
+// checkpoints and saved positions are left out of comparisons and hashing.
+impl<Tape: PartialEq> PartialEq for CollectionCursor<Tape> {
+	fn eq(&self, other: &Self) -> bool {
+		self.inner == other.inner && self.pos == other.pos
+	}
+}
+
+impl<Tape: Eq> Eq for CollectionCursor<Tape> {}
+
+impl<Tape: PartialOrd> PartialOrd for CollectionCursor<Tape> {
+	fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+		match self.inner.partial_cmp(&other.inner) {
+			Some(Ordering::Equal) => self.pos.partial_cmp(&other.pos),
+			ord => ord,
+		}
+	}
+}
+
+impl<Tape: Ord> Ord for CollectionCursor<Tape> {
+	fn cmp(&self, other: &Self) -> Ordering {
+		self.inner
+			.cmp(&other.inner)
+			.then_with(|| self.pos.cmp(&other.pos))
+	}
+}
+
+impl<Tape: Hash> Hash for CollectionCursor<Tape> {
+	fn hash<H: Hasher>(&self, state: &mut H) {
+		self.inner.hash(state);
+		self.pos.hash(state);
+	}
+}
+
+// Implemented manually so that the position is shown relative to the collection's length, i.e.
+// `pos: 5/10`.
+impl<Tape: IndexableCollection + fmt::Debug> fmt::Debug for CollectionCursor<Tape> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_struct("CollectionCursor")
+			.field("pos", &format_args!("{}/{}", self.pos, self.inner.len()))
+			.field("inner", &self.inner)
+			.finish()
+	}
+}
+
+/// Formats a [`CollectionCursor`] like its `Debug` implementation, but without the collection's
+/// length. This is created by [`CollectionCursor::debug_without_len()`].
+struct DebugWithoutLen<'a, Tape>(&'a CollectionCursor<Tape>);
+
+impl<Tape: fmt::Debug> fmt::Debug for DebugWithoutLen<'_, Tape> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_struct("CollectionCursor")
+			.field("pos", &self.0.pos)
+			.field("inner", &self.0.inner)
+			.finish()
+	}
+}
+
+/// Inserts each item at the cursor, and moves the cursor past it, as with
+/// [`CollectionCursor::append_from()`]. As such, the items end up in order, starting at the
+/// position the cursor was at before extending.
+impl<Tape: IndexableCollectionResizable> Extend<Tape::Item> for CollectionCursor<Tape> {
+	fn extend<I: IntoIterator<Item = Tape::Item>>(&mut self, iter: I) {
+		self.append_from(iter);
+	}
+}
+
+/// Creates a cursor over a new collection containing the items of the iterator, in order. The
+/// cursor's initial position will always be `0`.
+impl<Tape: IndexableCollectionResizable + Default> FromIterator<Tape::Item>
+	for CollectionCursor<Tape>
+{
+	fn from_iter<I: IntoIterator<Item = Tape::Item>>(iter: I) -> Self {
+		let mut cursor = Self::new(Tape::default());
+		cursor.extend(iter);
+		cursor.pos = 0;
+		cursor
+	}
+}
+
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SeekFrom {
+	/// Moves the cursor to the provided index.
+	///
+	/// # Examples
+	/// * `SeekFrom::Start(0)` will move the cursor to the first item
+	/// * `SeekFrom::Start(5)` will move the cursor to the sixth item
+	Start(usize),
+	/// Moves the cursor to the cassette's length (as provided by [`IndexableCollection::len`]) plus
+	/// the provided number of indices.
+	///
+	/// It is an error to seek before the first index, or more than one index past the last item.
+	///
+	/// # Examples
+	/// * `SeekFrom::End(-1)` will move the cursor to the last item, if one exists
+	/// * `SeekFrom::End(0)` will move the cursor to the index just after the last item
+	End(isize),
+	/// Moves the cursor to the current position (as provided by [`CollectionCursor::position`])
+	/// plus the provided number of indices.
+	///
+	/// It is an error to seek before the first index, or more than one index past the last item.
 	///
 	/// # Examples
 	/// * `SeekFrom::Current(-2)` will move the cursor back two indices
@@ -257,6 +1409,103 @@ pub enum SeekFrom {
 	Current(isize),
 }
 
+/// A recorded position of a [`CollectionCursor`], created by [`CollectionCursor::snapshot()`].
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct CursorSnapshot {
+	/// The position of the cursor when the snapshot was taken.
+	pos: usize,
+}
+
+impl CursorSnapshot {
+	/// Returns the position of the cursor when the snapshot was taken.
+	pub fn position(&self) -> usize {
+		self.pos
+	}
+}
+
+/// Returns `result`, the result of a checked calculation involving the cursor's position.
+///
+/// When the `strict-arithmetic` feature is enabled and debug assertions are on, this instead
+/// panics with `message` if the calculation overflowed (that is, if `result` is `None`).
+#[track_caller]
+pub(crate) fn strict_checked<T>(result: Option<T>, message: fmt::Arguments<'_>) -> Option<T> {
+	#[cfg(feature = "strict-arithmetic")]
+	debug_assert!(result.is_some(), "{message}");
+	#[cfg(not(feature = "strict-arithmetic"))]
+	let _ = message;
+
+	result
+}
+
+/// An error returned when attempting to move the cursor outside the bounds of the collection.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct OutOfBoundsError {
+	/// Which bound was exceeded.
+	kind: OutOfBoundsKind,
+	/// The position that the cursor would have been moved to, or `None` if that position could
+	/// not be represented by a `usize`.
+	attempted: Option<usize>,
+	/// The length of the collection at the time of the attempt.
+	collection_len: usize,
+}
+
+impl OutOfBoundsError {
+	/// Returns which bound of the collection the attempted position was outside of.
+	pub fn kind(&self) -> OutOfBoundsKind {
+		self.kind
+	}
+
+	/// Returns the position that the cursor would have been moved to.
+	///
+	/// Returns `None` if that position could not be represented by a `usize` - that is, if it
+	/// would have been before `0`, or after `usize::MAX`.
+	pub fn attempted(&self) -> Option<usize> {
+		self.attempted
+	}
+
+	/// Returns the length of the collection at the time of the attempt.
+	pub fn collection_len(&self) -> usize {
+		self.collection_len
+	}
+}
+
+impl fmt::Display for OutOfBoundsError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match (self.kind, self.attempted) {
+			(OutOfBoundsKind::PastEnd, Some(attempted)) => write!(
+				f,
+				"tried to seek to index {attempted} but collection length is {}",
+				self.collection_len
+			),
+			(OutOfBoundsKind::BeforeStart, _) => write!(
+				f,
+				"tried to seek before the start of the collection (collection length is {})",
+				self.collection_len
+			),
+			(OutOfBoundsKind::PastEnd | OutOfBoundsKind::Overflow, _) => write!(
+				f,
+				"tried to seek to an index outside the range of `usize` (collection length is {})",
+				self.collection_len
+			),
+		}
+	}
+}
+
+impl core::error::Error for OutOfBoundsError {}
+
+/// Which bound of the collection a seek went outside of, as reported by
+/// [`OutOfBoundsError::kind()`].
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum OutOfBoundsKind {
+	/// The attempted position was before index `0`.
+	BeforeStart,
+	/// The attempted position was after `self.get_ref().len()`.
+	PastEnd,
+	/// Calculating the attempted position overflowed past `usize::MAX` (or, for
+	/// [`CollectionCursor::seek_records()`], overflowed the offset).
+	Overflow,
+}
+
 #[allow(
 	clippy::len_without_is_empty,
 	reason = "While is_empty would normally be useful, we don't have a use for it here"
@@ -269,6 +1518,14 @@ pub trait IndexableCollection {
 	fn len(&self) -> usize;
 	/// Gets a reference to the item at index `index`. Returns `None` if no item exists at `index`.
 	fn get_item(&self, index: usize) -> Option<&Self::Item>;
+	/// Gets a slice containing all the items in this container, in order, if the items are
+	/// currently stored contiguously. Otherwise, returns `None`.
+	///
+	/// The default implementation always returns `None`. Collections which are always contiguous
+	/// should also implement [`ContiguousCollection`].
+	fn as_contiguous_slice(&self) -> Option<&[Self::Item]> {
+		None
+	}
 }
 
 pub trait IndexableCollectionMut: IndexableCollection {
@@ -281,6 +1538,21 @@ pub trait IndexableCollectionMut: IndexableCollection {
 	/// doesn't, then ensure you are following the "rule of least surprise" - whether through
 	/// documentation or otherwise.
 	fn set_item(&mut self, index: usize, element: Self::Item);
+	/// Gets the number of items this container can hold without reallocating.
+	///
+	/// The default implementation returns `self.len()`, which is correct for collections that
+	/// can't grow.
+	fn capacity(&self) -> usize {
+		self.len()
+	}
+	/// Reserves capacity for at least `additional` more items to be inserted, so that inserting
+	/// them won't reallocate.
+	///
+	/// The default implementation does nothing. Collections that can't grow, or that don't support
+	/// reserving capacity ahead of time, may ignore this.
+	fn reserve(&mut self, additional: usize) {
+		let _ = additional;
+	}
 }
 
 pub trait IndexableCollectionResizable: IndexableCollectionMut {
@@ -298,10 +1570,85 @@ pub trait IndexableCollectionResizable: IndexableCollectionMut {
 	/// the normal `remove()` method of a collection would panic given an invalid index, your
 	/// implementation must check and return `None` in those instances.
 	fn remove_item(&mut self, index: usize) -> Option<Self::Item>;
+	/// Removes the item at index `index` from the container, and returns the item, or `None` if no
+	/// item exists at index `index`. The last item of the container is moved into its place.
+	///
+	/// This does not preserve the order of the items, but avoids moving every item after `index`.
+	///
+	/// The default implementation removes the last item with [`Self::remove_item()`], and then
+	/// replaces the item at `index` with it.
+	fn swap_remove_item(&mut self, index: usize) -> Option<Self::Item> {
+		let last_index = self.len().checked_sub(1)?;
+		if index > last_index {
+			return None;
+		}
+
+		let last_item = self.remove_item(last_index)?;
+		if index == last_index {
+			return Some(last_item);
+		}
+
+		self.get_item_mut(index)
+			.map(|item| core::mem::replace(item, last_item))
+	}
+	/// Inserts the items of `iter` at index `index`, in order, moving the item at the index and
+	/// all items after it forward to make room. Returns the number of items inserted.
+	///
+	/// This is allowed (and generally expected) to panic if `index > self.len()`.
+	///
+	/// The default implementation reserves space for the lower bound of `iter`'s size hint, and
+	/// then inserts each item with [`Self::insert_item()`]. Collections which can insert many
+	/// items at once (such as `Vec`, through `splice()`) should override this.
+	fn insert_items<I: IntoIterator<Item = Self::Item>>(&mut self, index: usize, iter: I) -> usize
+	where
+		Self: Sized,
+	{
+		let iter = iter.into_iter();
+		self.reserve(iter.size_hint().0);
+
+		let mut inserted = 0;
+		for item in iter {
+			self.insert_item(index + inserted, item);
+			inserted += 1;
+		}
+		inserted
+	}
 	/// Clears the container's contents.
 	fn clear(&mut self);
 }
 
+pub trait ContiguousCollection: IndexableCollection {
+	/// Gets a slice containing all the items in this container, in order.
+	fn as_slice(&self) -> &[Self::Item];
+}
+
+pub trait ContiguousCollectionMut: ContiguousCollection + IndexableCollectionMut {
+	/// Gets a mutable slice containing all the items in this container, in order.
+	fn as_mut_slice(&mut self) -> &mut [Self::Item];
+}
+
+pub trait SplittableCollection: IndexableCollection + Sized {
+	/// Splits the container in two at index `at`. Afterwards, this container holds the items
+	/// before `at`, and the returned container holds the items from `at` onward, in order.
+	///
+	/// This is allowed (and generally expected) to panic if `at > self.len()`.
+	fn split_off(&mut self, at: usize) -> Self;
+}
+
+/// An extension trait for creating a [`CollectionCursor`] which borrows a collection, rather than
+/// taking ownership of it.
+pub trait CollectionCursorExt: IndexableCollection {
+	/// Creates a [`CollectionCursor`] which mutably borrows this collection. The cursor's initial
+	/// position will always be `0`.
+	///
+	/// Once the cursor is dropped, the collection can be used directly again.
+	fn cursor_mut(&mut self) -> CollectionCursor<&mut Self> {
+		CollectionCursor::new(self)
+	}
+}
+
+impl<C: IndexableCollection + ?Sized> CollectionCursorExt for C {}
+
 #[cfg(test)]
 mod collection_cursor_tests {
 	extern crate alloc;
@@ -312,7 +1659,7 @@ mod collection_cursor_tests {
 	type TestVec = Vec<i32>;
 	type TestCollection = CollectionCursor<TestVec>;
 
-	fn test_vec() -> TestVec {
+	pub(crate) fn test_vec() -> TestVec {
 		let res = Vec::from([0, 1, 2, 3, 4, 5, 9, 8, 7, 6]);
 
 		// Ensure that the length is a known value.
@@ -322,10 +1669,14 @@ mod collection_cursor_tests {
 		res
 	}
 
-	fn test_collection() -> TestCollection {
+	pub(crate) fn test_collection() -> TestCollection {
 		let res = CollectionCursor {
 			inner: self::test_vec(),
 			pos: Default::default(),
+			#[cfg(feature = "alloc")]
+			checkpoints: Default::default(),
+			#[cfg(feature = "alloc")]
+			position_stack: Default::default(),
 		};
 
 		// Ensure that the cursor position is a known value.
@@ -343,6 +1694,14 @@ mod collection_cursor_tests {
 		assert_eq!(new_collection, test_collection);
 	}
 
+	#[test]
+	fn new_in_const_context() {
+		static CURSOR: CollectionCursor<[u8; 4]> = CollectionCursor::new([0; 4]);
+
+		assert_eq!(CURSOR.position(), 0);
+		assert_eq!(CURSOR.get_ref(), &[0; 4]);
+	}
+
 	#[test]
 	fn position() {
 		let mut collection = self::test_collection();
@@ -373,6 +1732,22 @@ mod collection_cursor_tests {
 		assert_eq!(collection.into_inner(), self::test_vec());
 	}
 
+	#[test]
+	fn is_cursor_at_start() {
+		let mut collection = self::test_collection();
+
+		assert!(
+			collection.is_cursor_at_start(),
+			"should return true when at start"
+		);
+
+		collection.pos = 1;
+		assert!(
+			!collection.is_cursor_at_start(),
+			"should return false when not at start"
+		);
+	}
+
 	#[test]
 	fn is_cursor_at_end() {
 		let mut collection = self::test_collection();
@@ -389,6 +1764,29 @@ mod collection_cursor_tests {
 		);
 	}
 
+	#[test]
+	fn remaining() {
+		let mut collection = self::test_collection();
+
+		assert_eq!(collection.remaining(), 10);
+		collection.pos = 7;
+		assert_eq!(collection.remaining(), 3);
+		collection.pos = 10;
+		assert_eq!(collection.remaining(), 0);
+
+		collection.pos = 8;
+		collection.get_mut().truncate(5);
+		assert_eq!(
+			collection.remaining(),
+			0,
+			"should saturate when the cursor is past the end of the collection"
+		);
+		assert!(
+			!collection.is_cursor_at_end(),
+			"shouldn't consider a cursor past the end to be at the end"
+		);
+	}
+
 	#[test]
 	fn seek() {
 		fn inner(
@@ -398,7 +1796,7 @@ mod collection_cursor_tests {
 			expected_pos: usize,
 			error_message: &'static str,
 		) {
-			let new_pos = collection.seek(seek_from);
+			let new_pos = collection.seek(seek_from).ok();
 			assert_eq!(new_pos, expected_result, "{error_message}");
 			assert_eq!(
 				collection.pos, expected_pos,
@@ -408,8 +1806,7 @@ mod collection_cursor_tests {
 
 		let mut collection = self::test_collection();
 
-		// Seeking to within valid bounds should return the `Some(the new position)` and move the
-		// cursor
+		// Seeking to within valid bounds should return `Ok(the new position)` and move the cursor
 		inner(
 			&mut collection,
 			SeekFrom::Start(3),
@@ -494,7 +1891,7 @@ mod collection_cursor_tests {
 			"this shouldn't fail",
 		);
 
-		// Seeking outside valid bounds should return `None` and *not* move the cursor
+		// Seeking outside valid bounds should return an error and *not* move the cursor
 		inner(
 			&mut collection,
 			SeekFrom::Start(usize::MAX),
@@ -541,56 +1938,229 @@ mod collection_cursor_tests {
 		);
 	}
 
-	macro_rules! __clamp_to {
-		($method:tt, $first_test_expected_pos:expr, $first_test_error_message:expr) => {
-			let mut collection = self::test_collection();
-
-			collection.pos = usize::MAX;
-			collection.$method();
-			assert_eq!(
-				collection.pos, $first_test_expected_pos,
-				$first_test_error_message
-			);
-
-			collection.pos = 2;
-			collection.$method();
-			assert_eq!(
-				collection.pos, 2,
-				"shouldn't move the cursor when already within the bounds of the collection"
-			);
-
-			collection = CollectionCursor::new(Vec::from([]));
+	#[test]
+	fn seek_errors() {
+		let mut collection = self::test_collection();
 
-			collection.pos = 0;
-			collection.$method();
-			assert_eq!(
-				collection.pos, 0,
-				"should keep the cursor at index `0` when given an empty collection"
-			);
+		assert_eq!(
+			collection.seek(SeekFrom::Start(3)),
+			Ok(3),
+			"should return the new position when within the bounds of the collection"
+		);
+		assert_eq!(collection.pos, 3);
 
-			collection.pos = usize::MAX;
-			collection.$method();
-			assert_eq!(
-				collection.pos, 0,
-				"should keep the cursor at index `0` when given an empty collection"
-			);
-		};
+		assert_eq!(
+			collection.seek(SeekFrom::End(1)),
+			Err(OutOfBoundsError {
+				kind: OutOfBoundsKind::PastEnd,
+				attempted: Some(11),
+				collection_len: 10,
+			}),
+			"should return the attempted position when past the end of the collection"
+		);
+		assert_eq!(
+			collection.seek(SeekFrom::Current(-4)),
+			Err(OutOfBoundsError {
+				kind: OutOfBoundsKind::BeforeStart,
+				attempted: None,
+				collection_len: 10,
+			}),
+			"should report seeking before the start of the collection"
+		);
+		assert_eq!(collection.pos, 3, "shouldn't move the cursor on failure");
 	}
 
 	#[test]
-	fn clamp_to_last_item() {
-		let collection_len = self::test_collection().inner.len();
-
-		__clamp_to!(
-			clamp_to_last_item,
-			collection_len - 1,
-			"should move the cursor to the last item of the collection"
-		);
+	#[cfg(all(feature = "strict-arithmetic", debug_assertions))]
+	#[should_panic = "overflowed `usize`"]
+	fn strict_arithmetic_panics_on_overflow() {
+		let mut collection = self::test_collection();
+		collection.pos = usize::MAX;
+		let _ = collection.seek(SeekFrom::Current(1));
 	}
 
 	#[test]
-	fn clamp_to_end() {
-		let collection_len = self::test_collection().inner.len();
+	#[cfg(all(feature = "strict-arithmetic", debug_assertions))]
+	#[should_panic = "overflowed `usize`"]
+	fn strict_arithmetic_panics_on_relative_overflow() {
+		let mut collection = self::test_collection();
+		collection.pos = usize::MAX;
+		let _ = collection.seek_relative(1);
+	}
+
+	#[test]
+	#[cfg(not(all(feature = "strict-arithmetic", debug_assertions)))]
+	fn overflow_returns_error() {
+		let mut collection = self::test_collection();
+		collection.pos = usize::MAX;
+
+		assert_eq!(
+			collection.seek(SeekFrom::Current(1)),
+			Err(OutOfBoundsError {
+				kind: OutOfBoundsKind::Overflow,
+				attempted: None,
+				collection_len: 10,
+			}),
+			"should return an error when the position overflows"
+		);
+		assert_eq!(collection.pos, usize::MAX, "shouldn't move the cursor");
+	}
+
+	#[test]
+	fn seeking_before_start_never_panics() {
+		let mut collection = self::test_collection();
+
+		assert!(
+			collection.seek(SeekFrom::Current(-1)).is_err(),
+			"seeking before the start of the collection should never panic"
+		);
+		assert!(collection.seek(SeekFrom::End(isize::MIN)).is_err());
+	}
+
+	#[test]
+	fn seek_clamped() {
+		let mut collection = self::test_collection();
+
+		assert_eq!(collection.seek_clamped(SeekFrom::Start(4)), 4);
+		assert_eq!(collection.seek_clamped(SeekFrom::Current(-2)), 2);
+		assert_eq!(collection.seek_clamped(SeekFrom::End(-1)), 9);
+
+		assert_eq!(
+			collection.seek_clamped(SeekFrom::Start(20)),
+			10,
+			"should clamp to the end of the collection"
+		);
+		assert_eq!(
+			collection.seek_clamped(SeekFrom::Current(isize::MIN)),
+			0,
+			"should clamp to the start of the collection"
+		);
+		assert_eq!(
+			collection.seek_clamped(SeekFrom::End(isize::MAX)),
+			10,
+			"should clamp to the end of the collection"
+		);
+
+		collection.pos = usize::MAX;
+		assert_eq!(
+			collection.seek_clamped(SeekFrom::Current(isize::MAX)),
+			10,
+			"should saturate rather than overflow"
+		);
+		assert_eq!(collection.pos, 10);
+	}
+
+	#[test]
+	fn out_of_bounds_error_display() {
+		let error = OutOfBoundsError {
+			kind: OutOfBoundsKind::PastEnd,
+			attempted: Some(20),
+			collection_len: 10,
+		};
+		assert_eq!(
+			alloc::format!("{error}"),
+			"tried to seek to index 20 but collection length is 10"
+		);
+
+		let error = OutOfBoundsError {
+			kind: OutOfBoundsKind::BeforeStart,
+			attempted: None,
+			collection_len: 10,
+		};
+		assert_eq!(
+			alloc::format!("{error}"),
+			"tried to seek before the start of the collection (collection length is 10)"
+		);
+
+		let error = OutOfBoundsError {
+			kind: OutOfBoundsKind::Overflow,
+			attempted: None,
+			collection_len: 10,
+		};
+		assert_eq!(
+			alloc::format!("{error}"),
+			"tried to seek to an index outside the range of `usize` (collection length is 10)"
+		);
+	}
+
+	#[test]
+	fn debug() {
+		let mut collection = CollectionCursor::new(Vec::from([1, 2, 3]));
+		collection.pos = 2;
+
+		#[cfg(feature = "alloc")]
+		collection.set_checkpoint("checkpoint");
+
+		assert_eq!(
+			alloc::format!("{collection:?}"),
+			"CollectionCursor { pos: 2/3, inner: [1, 2, 3] }",
+			"should show the position relative to the length, without internal state such as \
+			 checkpoints"
+		);
+
+		/// A collection which isn't an `IndexableCollection`.
+		#[derive(Debug)]
+		struct NotIndexable;
+		assert_eq!(
+			alloc::format!(
+				"{:?}",
+				CollectionCursor::new(NotIndexable).debug_without_len()
+			),
+			"CollectionCursor { pos: 0, inner: NotIndexable }",
+			"should be usable for any collection which implements `Debug`"
+		);
+	}
+
+	macro_rules! __clamp_to {
+		($method:tt, $first_test_expected_pos:expr, $first_test_error_message:expr) => {
+			let mut collection = self::test_collection();
+
+			collection.pos = usize::MAX;
+			collection.$method();
+			assert_eq!(
+				collection.pos, $first_test_expected_pos,
+				$first_test_error_message
+			);
+
+			collection.pos = 2;
+			collection.$method();
+			assert_eq!(
+				collection.pos, 2,
+				"shouldn't move the cursor when already within the bounds of the collection"
+			);
+
+			collection = CollectionCursor::new(Vec::from([]));
+
+			collection.pos = 0;
+			collection.$method();
+			assert_eq!(
+				collection.pos, 0,
+				"should keep the cursor at index `0` when given an empty collection"
+			);
+
+			collection.pos = usize::MAX;
+			collection.$method();
+			assert_eq!(
+				collection.pos, 0,
+				"should keep the cursor at index `0` when given an empty collection"
+			);
+		};
+	}
+
+	#[test]
+	fn clamp_to_last_item() {
+		let collection_len = self::test_collection().inner.len();
+
+		__clamp_to!(
+			clamp_to_last_item,
+			collection_len - 1,
+			"should move the cursor to the last item of the collection"
+		);
+	}
+
+	#[test]
+	fn clamp_to_end() {
+		let collection_len = self::test_collection().inner.len();
 
 		__clamp_to!(
 			clamp_to_end,
@@ -599,6 +2169,39 @@ mod collection_cursor_tests {
 		);
 	}
 
+	#[test]
+	fn clamp_position_to() {
+		let mut collection = self::test_collection();
+
+		collection.pos = 8;
+		collection.clamp_position_to(2..=5);
+		assert_eq!(collection.pos, 5, "should clamp to the end of the range");
+
+		collection.pos = 0;
+		collection.clamp_position_to(2..=5);
+		assert_eq!(collection.pos, 2, "should clamp to the start of the range");
+
+		collection.pos = 3;
+		collection.clamp_position_to(2..=5);
+		assert_eq!(
+			collection.pos, 3,
+			"shouldn't move when already within the range"
+		);
+
+		collection.pos = usize::MAX;
+		collection.clamp_position_to(2..=usize::MAX);
+		assert_eq!(
+			collection.pos, 10,
+			"should clamp to the end of the collection when the range extends past it"
+		);
+
+		collection.clamp_position_to(20..=30);
+		assert_eq!(
+			collection.pos, 10,
+			"should clamp to the end of the collection when the range is past it"
+		);
+	}
+
 	#[test]
 	fn seek_backward_one() {
 		fn inner(
@@ -650,7 +2253,7 @@ mod collection_cursor_tests {
 			expected_pos: usize,
 			error_message: &'static str,
 		) {
-			let seek_res = collection.seek_relative(offset);
+			let seek_res = collection.seek_relative(offset).ok();
 			assert_eq!(seek_res, expected_result, "{error_message}");
 			assert_eq!(
 				collection.pos, expected_pos,
@@ -697,6 +2300,9 @@ mod collection_cursor_tests {
 		);
 
 		collection.pos = usize::MAX;
+		// With `strict-arithmetic`, this overflow panics instead. See
+		// `strict_arithmetic_panics_on_overflow()`.
+		#[cfg(not(all(feature = "strict-arithmetic", debug_assertions)))]
 		inner(
 			&mut collection,
 			5,
@@ -713,6 +2319,65 @@ mod collection_cursor_tests {
 		);
 	}
 
+	#[test]
+	fn seek_relative_errors() {
+		let mut collection = self::test_collection();
+		collection.pos = 5;
+
+		assert_eq!(
+			collection.seek_relative(-2),
+			Ok(3),
+			"should move when within the bounds of the collection"
+		);
+		assert_eq!(collection.pos, 3);
+
+		let error = collection.seek_relative(8).unwrap_err();
+		assert_eq!(error.kind(), OutOfBoundsKind::PastEnd);
+		assert_eq!(
+			error.attempted(),
+			Some(11),
+			"should report the attempted position when it's out of range"
+		);
+		assert_eq!(error.collection_len(), 10);
+
+		let error = collection.seek_relative(isize::MIN).unwrap_err();
+		assert_eq!(error.kind(), OutOfBoundsKind::BeforeStart);
+		assert_eq!(
+			error.attempted(),
+			None,
+			"should report no attempted position when it's before the start"
+		);
+		assert_eq!(collection.pos, 3, "shouldn't move the cursor on failure");
+	}
+
+	#[test]
+	fn seek_relative_reporting() {
+		let mut collection = self::test_collection();
+		collection.pos = 5;
+
+		assert_eq!(
+			collection.seek_relative_reporting(2),
+			Ok(7),
+			"should move when within the bounds of the collection"
+		);
+		assert_eq!(
+			collection.seek_relative_reporting(5),
+			Err(2),
+			"should report a positive overshoot past the end"
+		);
+		assert_eq!(
+			collection.seek_relative_reporting(-10),
+			Err(-3),
+			"should report a negative overshoot before the start"
+		);
+		assert_eq!(
+			collection.seek_relative_reporting(isize::MIN),
+			Err(isize::MIN + 7),
+			"should report the overshoot of even the largest offsets"
+		);
+		assert_eq!(collection.pos, 7, "shouldn't move the cursor on failure");
+	}
+
 	#[test]
 	fn seek_forward_one() {
 		fn inner(
@@ -765,6 +2430,9 @@ mod collection_cursor_tests {
 		);
 
 		collection.pos = usize::MAX;
+		// With `strict-arithmetic`, this overflow panics instead. See
+		// `strict_arithmetic_panics_on_overflow()`.
+		#[cfg(not(all(feature = "strict-arithmetic", debug_assertions)))]
 		inner(
 			&mut collection,
 			false,
@@ -804,6 +2472,20 @@ mod collection_cursor_tests {
 		__seek_to!(seek_to_end, usize::MAX, expected_pos);
 	}
 
+	#[test]
+	fn seek_to_middle() {
+		let mut collection = self::test_collection();
+		assert_eq!(collection.seek_to_middle(), 5);
+		assert_eq!(collection.pos, 5);
+
+		collection.get_mut().pop();
+		assert_eq!(collection.seek_to_middle(), 4, "should round down");
+
+		let mut empty = CollectionCursor::new(TestVec::new());
+		assert_eq!(empty.seek_to_middle(), 0);
+		assert_eq!(empty.pos, 0);
+	}
+
 	#[test]
 	fn get_item_at_cursor() {
 		let test_vec = self::test_vec();
@@ -823,103 +2505,1421 @@ mod collection_cursor_tests {
 	}
 
 	#[test]
-	fn clear() {
-		let mut test_vec = self::test_vec();
+	fn iter_from_cursor() {
+		let test_vec = self::test_vec();
 		let mut collection = self::test_collection();
 
-		test_vec.clear();
-		collection.clear();
-
-		assert_eq!(collection.inner, test_vec);
+		for i in 0..=(test_vec.len()) {
+			collection.pos = i;
+			assert!(
+				collection.iter_from_cursor().eq(&test_vec[i..]),
+				"should iterate over the items from the cursor onwards (index = `{i}`)"
+			);
+		}
 	}
 
 	#[test]
-	fn get_item_at_cursor_mut() {
-		let mut test_vec = self::test_vec();
+	fn iter_from_cursor_rev() {
 		let mut collection = self::test_collection();
+		collection.pos = 7;
 
-		// We deliberately request one item past the end, to test if that's also the same
-		for i in 0..=(test_vec.len()) {
-			collection.pos = i;
-			let test_vec_get = test_vec.get_mut(i);
-			let collection_get = collection.get_item_at_cursor_mut();
+		assert!(
+			collection.iter_from_cursor_rev().eq(&[6, 7, 8]),
+			"should iterate from the last item back to the cursor"
+		);
+		assert_eq!(collection.pos, 7, "shouldn't move the cursor");
 
-			assert_eq!(
-				test_vec_get, collection_get,
-				"should get the same item from the same index (index = `{i}`)"
-			);
-		}
+		collection.pos = collection.inner.len();
+		assert_eq!(collection.iter_from_cursor_rev().next(), None);
 	}
 
-	fn __set_item(mut collection: TestCollection, mut test_vec: TestVec) {
-		const AT_POS: usize = 5;
-		const TO_VALUE: i32 = 52345;
+	fn line_collection() -> CollectionCursor<Vec<u8>> {
+		// Lines start at indices `0`, `6`, and `18`. Newlines are at indices `5` and `17`.
+		CollectionCursor::new(Vec::from(*b"first\nsecond line\nthird"))
+	}
 
-		test_vec[AT_POS] = TO_VALUE;
-		collection.pos = AT_POS;
-		collection.set_item_at_cursor(TO_VALUE);
+	#[test]
+	fn seek_to_start_of_line() {
+		let is_newline = |item: &u8| *item == b'\n';
+		let mut collection = self::line_collection();
 
+		collection.pos = 9;
 		assert_eq!(
-			collection.inner.get(AT_POS),
-			Some(&TO_VALUE),
-			"should modify the inner collection to have the correct value at the head"
+			collection.seek_to_start_of_line(is_newline),
+			6,
+			"should move to just after the previous newline"
 		);
-		assert_eq!(collection.inner, test_vec, "should set only one value");
-	}
+		assert_eq!(collection.pos, 6);
 
-	#[test]
-	fn set_item_at_cursor() {
-		let test_vec = self::test_vec();
-		let collection = self::test_collection();
+		assert_eq!(
+			collection.seek_to_start_of_line(is_newline),
+			6,
+			"shouldn't move when already at the start of a line"
+		);
 
-		self::__set_item(collection, test_vec);
+		collection.pos = 3;
+		assert_eq!(
+			collection.seek_to_start_of_line(is_newline),
+			0,
+			"should move to the start of the collection when on the first line"
+		);
 	}
 
-	fn __insert_item(mut collection: TestCollection, mut test_vec: TestVec) {
-		const AT_POS: usize = 5;
-		const TO_VALUE: i32 = 52345;
+	#[test]
+	fn seek_to_end_of_line() {
+		let is_newline = |item: &u8| *item == b'\n';
+		let mut collection = self::line_collection();
 
-		test_vec.insert(AT_POS, TO_VALUE);
-		collection.pos = AT_POS;
-		collection.insert_item_at_cursor(TO_VALUE);
+		collection.pos = 9;
+		assert_eq!(
+			collection.seek_to_end_of_line(is_newline),
+			17,
+			"should move onto the next newline"
+		);
+		assert_eq!(collection.pos, 17);
 
 		assert_eq!(
-			collection.inner.get(AT_POS),
-			Some(&TO_VALUE),
-			"should modify the inner collection to have the correct value at the head"
+			collection.seek_to_end_of_line(is_newline),
+			17,
+			"shouldn't move when already at the end of a line"
+		);
+
+		collection.pos = 20;
+		assert_eq!(
+			collection.seek_to_end_of_line(is_newline),
+			collection.inner.len(),
+			"should move to the end of the collection when on the last line"
 		);
-		assert_eq!(collection.inner, test_vec, "should insert only one value");
 	}
 
 	#[test]
-	fn insert_item_at_cursor() {
-		let test_vec = self::test_vec();
-		let collection = self::test_collection();
+	fn frame_length_from_cursor() {
+		let is_terminator = |item: &u8| *item == 0;
+		let mut collection = CollectionCursor::new(Vec::from([3u8, 1, 2, 0, 5, 6, 0, 7]));
 
-		self::__insert_item(collection, test_vec);
-	}
+		assert_eq!(
+			collection.frame_length_from_cursor(is_terminator),
+			Some(4),
+			"should include the terminator in the frame length"
+		);
 
-	#[test]
-	fn set_or_insert_item_at_cursor() {
-		let test_vec = self::test_vec();
-		let collection = self::test_collection();
+		collection.pos = 4;
+		assert_eq!(collection.frame_length_from_cursor(is_terminator), Some(3));
+		assert_eq!(collection.pos, 4, "shouldn't move the cursor");
 
-		self::__set_item(collection.clone(), test_vec.clone());
-		self::__insert_item(collection.clone(), test_vec.clone());
+		collection.pos = 7;
+		assert_eq!(
+			collection.frame_length_from_cursor(is_terminator),
+			None,
+			"should return `None` when no terminator remains"
+		);
 	}
 
 	#[test]
-	fn remove_item_at_cursor() {
-		const AT_POS: usize = 5;
-
-		let mut test_vec = self::test_vec();
+	fn consume_subsequence() {
 		let mut collection = self::test_collection();
+		collection.pos = 2;
 
-		let test_vec_res = test_vec.remove(5);
-		collection.pos = AT_POS;
-		let collection_res = collection.remove_item_at_cursor();
-		assert_eq!(
-			collection_res,
+		assert!(
+			collection.consume_subsequence(&[2, 3, 4]),
+			"should match a pattern starting at the cursor"
+		);
+		assert_eq!(collection.pos, 5, "should move the cursor past the match");
+
+		assert!(
+			!collection.consume_subsequence(&[9, 8]),
+			"shouldn't match a pattern that only occurs after the cursor"
+		);
+		assert!(
+			!collection.consume_subsequence(&[5, 9, 8, 7, 6, 0]),
+			"shouldn't match a pattern that runs past the end of the collection"
+		);
+		assert_eq!(
+			collection.pos, 5,
+			"shouldn't move the cursor without a match"
+		);
+
+		assert!(
+			collection.consume_subsequence(&[]),
+			"should always match an empty pattern"
+		);
+		assert_eq!(collection.pos, 5);
+	}
+
+	#[test]
+	fn cursor_mut() {
+		let mut test_vec = self::test_vec();
+
+		{
+			let mut collection = test_vec.cursor_mut();
+			collection.seek(SeekFrom::Start(2)).unwrap();
+			collection.set_item_at_cursor(52345);
+			collection.seek_to_end();
+			collection.insert_item_at_cursor(12345);
+		}
+
+		assert_eq!(
+			test_vec,
+			Vec::from([0, 1, 52345, 3, 4, 5, 9, 8, 7, 6, 12345]),
+			"changes made through the cursor should be reflected in the original collection"
+		);
+	}
+
+	#[test]
+	fn move_forward() {
+		let mut collection = self::test_collection();
+
+		assert_eq!(collection.move_forward(3), 3);
+		assert_eq!(collection.pos, 3);
+
+		assert_eq!(
+			collection.move_forward(100),
+			10,
+			"should stop at the end of the collection"
+		);
+		assert_eq!(collection.pos, 10);
+
+		assert_eq!(
+			collection.move_forward(usize::MAX),
+			10,
+			"shouldn't overflow"
+		);
+	}
+
+	#[test]
+	fn move_backward() {
+		let mut collection = self::test_collection();
+		collection.pos = 8;
+
+		assert_eq!(collection.move_backward(3), 5);
+		assert_eq!(collection.pos, 5);
+
+		assert_eq!(
+			collection.move_backward(100),
+			0,
+			"should stop at the start of the collection"
+		);
+		assert_eq!(collection.pos, 0);
+	}
+
+	#[test]
+	fn remaining_eq() {
+		let mut collection = self::test_collection();
+		collection.pos = 6;
+
+		assert!(
+			collection.remaining_eq(&[9, 8, 7, 6]),
+			"should be equal to the remaining items"
+		);
+		assert!(
+			!collection.remaining_eq(&[9, 8, 7]),
+			"shouldn't be equal to a shorter slice"
+		);
+		assert!(
+			!collection.remaining_eq(&[9, 8, 7, 6, 5]),
+			"shouldn't be equal to a longer slice"
+		);
+		assert!(
+			!collection.remaining_eq(&[9, 8, 7, 0]),
+			"shouldn't be equal to a slice with different items"
+		);
+
+		collection.pos = 10;
+		assert!(
+			collection.remaining_eq(&[]),
+			"should be equal to an empty slice at the end"
+		);
+	}
+
+	#[test]
+	fn seek_to_max() {
+		let mut collection = self::test_collection();
+
+		assert_eq!(
+			collection.seek_to_max(),
+			Some(6),
+			"should find the largest item"
+		);
+		assert_eq!(
+			collection.pos, 6,
+			"should move the cursor onto the largest item"
+		);
+
+		collection.pos = 7;
+		assert_eq!(
+			collection.seek_to_max(),
+			Some(7),
+			"should only consider items at or after the cursor"
+		);
+
+		collection = CollectionCursor::new(Vec::from([3, 1, 3]));
+		assert_eq!(
+			collection.seek_to_max(),
+			Some(2),
+			"should choose the last of several equal items"
+		);
+
+		collection.pos = 3;
+		assert_eq!(
+			collection.seek_to_max(),
+			None,
+			"should return `None` when no items remain"
+		);
+		assert_eq!(collection.pos, 3, "shouldn't move the cursor");
+	}
+
+	#[test]
+	fn seek_to_min() {
+		let mut collection = self::test_collection();
+		collection.pos = 5;
+
+		assert_eq!(
+			collection.seek_to_min(),
+			Some(5),
+			"should find the smallest item"
+		);
+
+		collection.pos = 6;
+		assert_eq!(
+			collection.seek_to_min(),
+			Some(9),
+			"should only consider items at or after the cursor"
+		);
+		assert_eq!(
+			collection.pos, 9,
+			"should move the cursor onto the smallest item"
+		);
+
+		collection = CollectionCursor::new(Vec::from([3, 1, 1]));
+		assert_eq!(
+			collection.seek_to_min(),
+			Some(1),
+			"should choose the first of several equal items"
+		);
+
+		collection.pos = 3;
+		assert_eq!(
+			collection.seek_to_min(),
+			None,
+			"should return `None` when no items remain"
+		);
+		assert_eq!(collection.pos, 3, "shouldn't move the cursor");
+	}
+
+	#[test]
+	fn binary_search_seek() {
+		let mut collection = CollectionCursor::new(Vec::from([1, 3, 5, 7, 9]));
+
+		assert_eq!(collection.binary_search_seek(&7), Ok(3));
+		assert_eq!(collection.pos, 3, "should move onto the found item");
+
+		assert_eq!(collection.binary_search_seek(&4), Err(2));
+		assert_eq!(collection.pos, 2, "should move to the insertion point");
+
+		assert_eq!(collection.binary_search_seek(&10), Err(5));
+		assert_eq!(collection.pos, 5, "should move to the end");
+
+		assert_eq!(collection.binary_search_seek(&0), Err(0));
+		assert_eq!(collection.pos, 0, "should move to the start");
+
+		let mut empty = CollectionCursor::new(Vec::<i32>::new());
+		assert_eq!(empty.binary_search_seek(&1), Err(0));
+		assert_eq!(empty.pos, 0);
+	}
+
+	#[test]
+	fn content_eq_with() {
+		use alloc::collections::VecDeque;
+
+		let mut collection = self::test_collection();
+		collection.pos = 3;
+		let mut other = CollectionCursor::new(VecDeque::from(self::test_vec()));
+		other.pos = 3;
+
+		assert!(
+			collection.content_eq_with(&other),
+			"should be equal to a cursor with the same items and position"
+		);
+
+		other.pos = 4;
+		assert!(
+			!collection.content_eq_with(&other),
+			"shouldn't be equal to a cursor at a different position"
+		);
+
+		other.pos = 3;
+		other.inner.push_back(10);
+		assert!(
+			!collection.content_eq_with(&other),
+			"shouldn't be equal to a cursor with a different length"
+		);
+
+		other.inner.pop_back();
+		other.inner[0] = 10;
+		assert!(
+			!collection.content_eq_with(&other),
+			"shouldn't be equal to a cursor with different items"
+		);
+	}
+
+	#[test]
+	fn peek_ahead_iter() {
+		let mut collection = self::test_collection();
+		collection.pos = 2;
+
+		assert_eq!(
+			collection.peek_ahead_iter(4).collect::<Vec<_>>(),
+			Vec::from([&2, &3, &4, &5]),
+			"should yield `k` items starting at the cursor"
+		);
+		assert_eq!(collection.pos, 2, "shouldn't move the cursor");
+
+		collection.pos = 7;
+		assert_eq!(
+			collection.peek_ahead_iter(4).collect::<Vec<_>>(),
+			Vec::from([&8, &7, &6]),
+			"should yield fewer items when near the end"
+		);
+	}
+
+	#[test]
+	fn position_is_valid() {
+		let mut collection = self::test_collection();
+		assert!(collection.position_is_valid());
+
+		collection.pos = collection.inner.len();
+		assert!(
+			collection.position_is_valid(),
+			"should consider the end of the collection valid"
+		);
+
+		collection.pos = collection.inner.len() + 1;
+		assert!(
+			!collection.position_is_valid(),
+			"shouldn't consider a position past the end valid"
+		);
+	}
+
+	#[test]
+	fn remaining_slice() {
+		let mut collection = self::test_collection();
+		collection.pos = 6;
+
+		assert_eq!(collection.remaining_slice(), &[9, 8, 7, 6]);
+
+		collection.pos = collection.inner.len() + 1;
+		assert_eq!(
+			collection.remaining_slice(),
+			&[] as &[i32],
+			"should be empty when past the end"
+		);
+	}
+
+	#[test]
+	fn slices_around_cursor() {
+		let mut collection = self::test_collection();
+		collection.pos = 6;
+
+		assert_eq!(
+			collection.slices_around_cursor(),
+			(&[0, 1, 2, 3, 4, 5][..], &[9, 8, 7, 6][..])
+		);
+
+		collection.pos = collection.inner.len() + 1;
+		assert_eq!(
+			collection.slices_around_cursor(),
+			(&collection.inner[..], &[] as &[i32]),
+			"should treat a cursor past the end as being at the end"
+		);
+
+		let array_collection = CollectionCursor::new([1, 2, 3]);
+		assert_eq!(
+			array_collection.slices_around_cursor(),
+			(&[] as &[i32], &[1, 2, 3][..])
+		);
+	}
+
+	#[cfg(feature = "generic-array")]
+	#[test]
+	fn remaining_slice_generic_array() {
+		use generic_array::GenericArray;
+
+		let mut collection = CollectionCursor::new(GenericArray::from_array([0, 1, 2, 3, 4]));
+		collection.pos = 3;
+
+		assert_eq!(
+			collection.remaining_slice(),
+			&[3, 4],
+			"should treat `GenericArray` as contiguous"
+		);
+
+		// Fixed-size reads over a `GenericArray`-backed cursor
+		assert_eq!(
+			collection.read_array_from_cursor::<2>(),
+			Some([3, 4]),
+			"should read a window starting at the cursor"
+		);
+
+		collection.pos = 1;
+		collection.for_each_window_mut::<2>(|window| window.swap(0, 1));
+		assert_eq!(
+			collection.get_ref().as_slice(),
+			&[0, 2, 1, 4, 3],
+			"should allow modifying each window after the cursor"
+		);
+	}
+
+	#[test]
+	fn match_positions() {
+		let mut collection = self::test_collection();
+
+		assert_eq!(
+			collection
+				.match_positions(|item| item % 2 == 0)
+				.collect::<Vec<_>>(),
+			Vec::from([0, 2, 4, 7, 9]),
+			"should yield the index of every matching item"
+		);
+
+		collection.pos = 5;
+		assert_eq!(
+			collection
+				.match_positions(|item| item % 2 == 0)
+				.collect::<Vec<_>>(),
+			Vec::from([7, 9]),
+			"should only yield matching items at or after the cursor"
+		);
+		assert_eq!(collection.pos, 5, "shouldn't move the cursor");
+	}
+
+	#[test]
+	fn items_equal_at() {
+		let collection = self::test_collection();
+
+		assert!(
+			collection.items_equal_at(5, &[5, 9, 8]),
+			"should match the items at the given index"
+		);
+		assert!(
+			!collection.items_equal_at(4, &[5, 9, 8]),
+			"shouldn't match the items at a different index"
+		);
+		assert!(
+			!collection.items_equal_at(8, &[7, 6, 5]),
+			"shouldn't match when running past the end"
+		);
+		assert!(
+			!collection.items_equal_at(usize::MAX, &[0]),
+			"shouldn't match when the end index overflows"
+		);
+		assert_eq!(collection.pos, 0, "shouldn't move the cursor");
+	}
+
+	#[test]
+	fn seek_records() {
+		let mut collection = CollectionCursor::new(Vec::from([0; 12]));
+
+		assert_eq!(
+			collection.seek_records(1, 3),
+			Ok(3),
+			"should move forward by one record"
+		);
+		assert_eq!(collection.seek_records(2, 3), Ok(9));
+		assert_eq!(
+			collection.seek_records(-1, 3),
+			Ok(6),
+			"should move backward by one record"
+		);
+		assert_eq!(
+			collection.seek_records(3, 3),
+			Err(OutOfBoundsError {
+				kind: OutOfBoundsKind::PastEnd,
+				attempted: Some(15),
+				collection_len: 12,
+			}),
+			"should fail when moving past the end"
+		);
+		assert_eq!(
+			collection.seek_records(5, 0),
+			Ok(6),
+			"shouldn't move with a width of `0`"
+		);
+		// With `strict-arithmetic`, this overflow panics instead. See
+		// `strict_arithmetic_panics_on_records_overflow()`.
+		#[cfg(not(all(feature = "strict-arithmetic", debug_assertions)))]
+		assert_eq!(
+			collection.seek_records(isize::MAX, 3),
+			Err(OutOfBoundsError {
+				kind: OutOfBoundsKind::Overflow,
+				attempted: None,
+				collection_len: 12,
+			}),
+			"should fail when the offset overflows"
+		);
+		assert_eq!(collection.pos, 6);
+
+		collection.pos = 15;
+		assert_eq!(
+			collection.seek_records(5, 0),
+			Err(OutOfBoundsError {
+				kind: OutOfBoundsKind::PastEnd,
+				attempted: Some(15),
+				collection_len: 12,
+			}),
+			"should fail with a width of `0` when already past the end"
+		);
+		assert_eq!(collection.pos, 15, "shouldn't move the cursor on failure");
+	}
+
+	#[test]
+	#[cfg(all(feature = "strict-arithmetic", debug_assertions))]
+	#[should_panic = "overflowed `isize`"]
+	fn strict_arithmetic_panics_on_records_overflow() {
+		let mut collection = CollectionCursor::new(Vec::from([0; 12]));
+		let _ = collection.seek_records(isize::MAX, 3);
+	}
+
+	#[test]
+	fn runs_from_cursor() {
+		let mut collection = CollectionCursor::new(Vec::from([0, 0, 1, 1, 2, 3, 3, 3]));
+		collection.pos = 2;
+
+		assert_eq!(
+			collection.runs_from_cursor().collect::<Vec<_>>(),
+			Vec::from([2..4, 4..5, 5..8]),
+			"should yield the range of each run at or after the cursor"
+		);
+		assert_eq!(collection.pos, 2, "shouldn't move the cursor");
+
+		collection.pos = collection.inner.len();
+		assert_eq!(
+			collection.runs_from_cursor().next(),
+			None,
+			"should yield nothing at the end"
+		);
+	}
+
+	#[test]
+	fn advance_while_fold() {
+		let mut collection = self::test_collection();
+		collection.pos = 1;
+
+		let sum = collection.advance_while_fold(0, |item| *item < 5, |acc, item| acc + item);
+		assert_eq!(sum, 1 + 2 + 3 + 4, "should fold each passed item");
+		assert_eq!(
+			collection.pos, 5,
+			"should stop at the first item that doesn't match"
+		);
+
+		let sum = collection.advance_while_fold(0, |item| *item < 5, |acc, item| acc + item);
+		assert_eq!(sum, 0, "should return `init` if no items match");
+		assert_eq!(collection.pos, 5, "shouldn't move if no items match");
+
+		let count = collection.advance_while_fold(0, |_| true, |acc, _| acc + 1);
+		assert_eq!(count, 5);
+		assert_eq!(collection.pos, 10, "should stop at the end");
+	}
+
+	#[test]
+	fn swap_with() {
+		let mut front = self::test_collection();
+		front.pos = 3;
+		let mut back = CollectionCursor::new(Vec::from([42, 43]));
+		back.pos = 1;
+
+		#[cfg(feature = "alloc")]
+		front.push_position();
+		front.swap_with(&mut back);
+		assert_eq!(
+			front.inner,
+			Vec::from([42, 43]),
+			"should swap the collections"
+		);
+		assert_eq!(front.pos, 1, "should swap the positions");
+		assert_eq!(back.inner, self::test_vec());
+		assert_eq!(back.pos, 3);
+
+		#[cfg(feature = "alloc")]
+		{
+			assert!(
+				front.marks().is_empty(),
+				"should swap the saved positions along with the collections"
+			);
+			assert_eq!(back.marks(), &[3]);
+		}
+	}
+
+	#[test]
+	fn snapshot() {
+		let mut collection = self::test_collection();
+		collection.pos = 2;
+
+		let snapshot = collection.snapshot();
+		assert_eq!(snapshot.position(), 2);
+
+		collection.seek_relative(3).unwrap();
+		assert_eq!(
+			collection.advanced_since(snapshot),
+			3,
+			"should report how far the cursor moved forward"
+		);
+
+		collection.pos = 0;
+		assert_eq!(
+			collection.advanced_since(snapshot),
+			-2,
+			"should report how far the cursor moved backward"
+		);
+
+		assert_eq!(collection.restore(snapshot), 2);
+		assert_eq!(collection.pos, 2, "should return to the recorded position");
+
+		collection.pos = 9;
+		let snapshot = collection.snapshot();
+		collection.inner.truncate(4);
+		assert_eq!(
+			collection.restore(snapshot),
+			4,
+			"should clamp a stale position to the end of the collection"
+		);
+	}
+
+	#[test]
+	fn try_restore() {
+		let mut collection = self::test_collection();
+		collection.pos = 4;
+		let snapshot = collection.snapshot();
+
+		collection.pos = 7;
+		assert!(collection.try_restore(snapshot));
+		assert_eq!(collection.pos, 4, "should return to the recorded position");
+
+		collection.pos = 9;
+		let stale_snapshot = collection.snapshot();
+		collection.inner.truncate(6);
+		collection.pos = 2;
+		assert!(
+			!collection.try_restore(stale_snapshot),
+			"should report a position past the end of the shrunk collection as stale"
+		);
+		assert_eq!(
+			collection.pos, 2,
+			"shouldn't move when the snapshot is stale"
+		);
+
+		assert!(
+			collection.try_restore(snapshot),
+			"should still restore a position which remains in bounds"
+		);
+		assert_eq!(collection.pos, 4);
+	}
+
+	#[test]
+	fn with_bookmark() {
+		let mut collection = self::test_collection();
+		collection.pos = 2;
+
+		let res = collection.with_bookmark(|cursor| {
+			cursor.seek_relative(3).unwrap();
+			Ok::<_, ()>(*cursor.get_item_at_cursor().unwrap())
+		});
+		assert_eq!(res, Ok(5));
+		assert_eq!(collection.pos, 5, "should keep the new position on success");
+
+		let res = collection.with_bookmark(|cursor| {
+			cursor.seek_relative(3).unwrap();
+			Err::<(), _>("no match")
+		});
+		assert_eq!(res, Err("no match"));
+		assert_eq!(
+			collection.pos, 5,
+			"should return to the bookmark on failure"
+		);
+
+		collection.pos = 8;
+		let res = collection.with_bookmark(|cursor| {
+			cursor.get_mut().truncate(4);
+			cursor.pos = 0;
+			Err::<(), _>("no match")
+		});
+		assert_eq!(res, Err("no match"));
+		assert_eq!(
+			collection.pos, 4,
+			"should clamp the bookmark if the collection shrank"
+		);
+	}
+
+	#[test]
+	fn get_item_at_cursor_or_else() {
+		let mut collection = self::test_collection();
+		collection.pos = 6;
+
+		assert_eq!(
+			collection.get_item_at_cursor_or_else(|| unreachable!()),
+			9,
+			"should return the item at the cursor without calling the closure"
+		);
+
+		collection.pos = collection.inner.len();
+		assert_eq!(
+			collection.get_item_at_cursor_or_else(|| -1),
+			-1,
+			"should call the closure if the cursor is out-of-bounds"
+		);
+	}
+
+	#[test]
+	fn position_of_prev_match() {
+		let mut collection = self::test_collection();
+		collection.pos = 5;
+
+		assert_eq!(
+			collection.position_of_prev_match(|item| item % 2 == 0),
+			Some(4),
+			"should find the nearest matching item before the cursor"
+		);
+		assert_eq!(
+			collection.position_of_prev_match(|item| *item == 5),
+			None,
+			"shouldn't consider the item at the cursor"
+		);
+		assert_eq!(collection.pos, 5, "shouldn't move the cursor");
+
+		collection.pos = 0;
+		assert_eq!(
+			collection.position_of_prev_match(|_| true),
+			None,
+			"should return `None` at the start"
+		);
+	}
+
+	#[test]
+	fn cycle_from_cursor() {
+		let mut collection = self::test_collection();
+		collection.pos = 7;
+
+		assert_eq!(
+			collection.cycle_from_cursor().take(7).collect::<Vec<_>>(),
+			Vec::from([&8, &7, &6, &8, &7, &6, &8]),
+			"should repeat the items from the cursor onwards"
+		);
+
+		collection.pos = collection.inner.len();
+		assert_eq!(
+			collection.cycle_from_cursor().next(),
+			None,
+			"should be empty when no items remain"
+		);
+	}
+
+	#[test]
+	fn prefixes_from_cursor() {
+		let mut collection = self::test_collection();
+		collection.pos = 7;
+
+		assert_eq!(
+			collection.prefixes_from_cursor().collect::<Vec<_>>(),
+			Vec::from([&[8][..], &[8, 7], &[8, 7, 6]]),
+			"should yield growing slices from the cursor"
+		);
+		assert_eq!(
+			collection.prefixes_from_cursor().last(),
+			Some(collection.remaining_slice()),
+			"should end with the remaining slice"
+		);
+
+		collection.pos = collection.inner.len();
+		assert_eq!(
+			collection.prefixes_from_cursor().next(),
+			None,
+			"should be empty when no items remain"
+		);
+	}
+
+	/// Ensures that cursors over `Vec` and `VecDeque` can use the methods of each trait, as both
+	/// collections implement the traits the cursor's methods require.
+	#[test]
+	fn vec_and_vec_deque_cursors() {
+		use alloc::collections::VecDeque;
+
+		let mut collection = CollectionCursor::new(Vec::from([1, 2, 3]));
+		assert_eq!(collection.seek(SeekFrom::Start(1)), Ok(1));
+		assert_eq!(collection.get_item_at_cursor(), Some(&2));
+		collection.set_item_at_cursor(5);
+		assert_eq!(collection.get_ref(), &Vec::from([1, 5, 3]));
+
+		let mut collection = CollectionCursor::new(VecDeque::from([1, 2, 3]));
+		assert_eq!(collection.seek(SeekFrom::Start(1)), Ok(1));
+		assert_eq!(collection.get_item_at_cursor(), Some(&2));
+		collection.set_item_at_cursor(5);
+		assert_eq!(collection.get_ref(), &VecDeque::from([1, 5, 3]));
+	}
+
+	#[test]
+	fn cmp_remaining() {
+		use core::cmp::Ordering;
+
+		let mut collection = CollectionCursor::new(Vec::from([9, 9, 1, 2]));
+		collection.pos = 2;
+		let mut other = CollectionCursor::new(Vec::from([0, 1, 3]));
+		other.pos = 1;
+
+		assert!(
+			collection > other,
+			"sanity check: the `Ord` implementation compares entire collections"
+		);
+		assert_eq!(
+			collection.cmp_remaining(&other),
+			Ordering::Less,
+			"should only compare the items from each cursor onwards"
+		);
+		assert_eq!(other.cmp_remaining(&collection), Ordering::Greater);
+
+		other.inner = Vec::from([5, 1, 2]);
+		assert_eq!(
+			collection.cmp_remaining(&other),
+			Ordering::Equal,
+			"should be equal when the remaining items are equal"
+		);
+	}
+
+	#[test]
+	fn read_and_advance() {
+		let mut collection = self::test_collection();
+		collection.pos = 8;
+
+		assert_eq!(collection.read_and_advance(), Some(&7));
+		assert_eq!(collection.pos, 9, "should move past the item read");
+		assert_eq!(collection.read_and_advance(), Some(&6));
+		assert_eq!(collection.pos, 10, "should move past the item read");
+
+		assert_eq!(
+			collection.read_and_advance(),
+			None,
+			"should return `None` at the end"
+		);
+		assert_eq!(collection.pos, 10, "shouldn't move at the end");
+	}
+
+	#[test]
+	fn peek_ahead() {
+		let mut collection = self::test_collection();
+		collection.pos = 5;
+
+		assert_eq!(
+			collection.peek_ahead(0),
+			collection.get_item_at_cursor(),
+			"should return the item at the cursor when given `0`"
+		);
+		assert_eq!(collection.peek_ahead(3), Some(&7));
+		assert_eq!(
+			collection.peek_ahead(5),
+			None,
+			"should return `None` past the end"
+		);
+		assert_eq!(
+			collection.peek_ahead(usize::MAX),
+			None,
+			"should return `None` when the index overflows"
+		);
+		assert_eq!(collection.pos, 5, "shouldn't move the cursor");
+	}
+
+	#[test]
+	fn peek_behind() {
+		let mut collection = self::test_collection();
+		collection.pos = 5;
+
+		assert_eq!(
+			collection.peek_behind(0),
+			collection.get_item_at_cursor(),
+			"should return the item at the cursor when given `0`"
+		);
+		assert_eq!(collection.peek_behind(3), Some(&2));
+		assert_eq!(collection.peek_behind(5), Some(&0));
+		assert_eq!(
+			collection.peek_behind(6),
+			None,
+			"should return `None` before the start"
+		);
+		assert_eq!(collection.pos, 5, "shouldn't move the cursor");
+	}
+
+	#[test]
+	fn get_item_before_cursor() {
+		let mut collection = self::test_collection();
+		assert_eq!(
+			collection.get_item_before_cursor(),
+			None,
+			"should return `None` at the start"
+		);
+
+		collection.pos = 7;
+		assert_eq!(collection.get_item_before_cursor(), Some(&9));
+
+		collection.seek_to_end();
+		assert_eq!(collection.get_item_before_cursor(), Some(&6));
+	}
+
+	#[test]
+	fn advance_to() {
+		let mut collection = self::test_collection();
+		collection.pos = 2;
+
+		assert!(
+			collection.advance_to(|item| *item > 5),
+			"should return `true` when finding a match"
+		);
+		assert_eq!(collection.pos, 6, "should move onto the match");
+
+		assert!(
+			collection.advance_to(|item| *item > 5),
+			"should match the item at the cursor"
+		);
+		assert_eq!(collection.pos, 6, "shouldn't move when already on a match");
+
+		assert!(
+			!collection.advance_to(|item| *item == 0),
+			"should return `false` when there's no match"
+		);
+		assert_eq!(collection.pos, 10, "should move to the end");
+
+		collection.pos = 15;
+		assert!(!collection.advance_to(|_| true));
+		assert_eq!(
+			collection.pos, 10,
+			"should move back to the end when past the end"
+		);
+	}
+
+	#[test]
+	fn find_from_cursor() {
+		let mut collection = self::test_collection();
+
+		assert_eq!(collection.find_from_cursor(|item| *item == 9), Some(6));
+		assert_eq!(collection.pos, 0, "shouldn't move the cursor");
+
+		collection.pos = 7;
+		assert_eq!(
+			collection.find_from_cursor(|item| *item == 9),
+			None,
+			"shouldn't find items before the cursor"
+		);
+		assert_eq!(
+			collection.find_from_cursor(|item| *item == 6),
+			Some(9),
+			"should return an absolute index"
+		);
+	}
+
+	#[test]
+	fn seek_to_next() {
+		let mut collection = self::test_collection();
+		collection.pos = 2;
+
+		assert_eq!(collection.seek_to_next(|item| *item > 5), Some(6));
+		assert_eq!(collection.pos, 6, "should move onto the match");
+		assert_eq!(
+			collection.seek_to_next(|item| *item > 5),
+			Some(6),
+			"should match the item at the cursor"
+		);
+
+		assert_eq!(
+			collection.seek_to_next(|item| *item == 0),
+			None,
+			"should return `None` when there's no match"
+		);
+		assert_eq!(collection.pos, 6, "shouldn't move when there's no match");
+	}
+
+	#[test]
+	fn seek_to_prev() {
+		let mut collection = self::test_collection();
+		collection.pos = 8;
+
+		assert_eq!(collection.seek_to_prev(|item| *item < 5), Some(4));
+		assert_eq!(collection.pos, 4, "should move onto the nearest match");
+		assert_eq!(
+			collection.seek_to_prev(|item| *item == 4),
+			None,
+			"shouldn't match the item at the cursor"
+		);
+		assert_eq!(collection.pos, 4, "shouldn't move when there's no match");
+	}
+
+	#[test]
+	fn seek_to_next_record() {
+		const HEADER: i32 = -1;
+		let mut collection = CollectionCursor::new(Vec::from([HEADER, 1, 2, HEADER, 3, 4, 5]));
+
+		assert_eq!(
+			collection.seek_to_next_record(|item| *item == HEADER),
+			Some(3),
+			"should skip the header at the cursor"
+		);
+		assert_eq!(collection.pos, 3, "should move onto the next header");
+
+		assert_eq!(
+			collection.seek_to_next_record(|item| *item == HEADER),
+			None,
+			"should return `None` when there are no more records"
+		);
+		assert_eq!(
+			collection.pos, 3,
+			"shouldn't move when there are no more records"
+		);
+	}
+
+	#[test]
+	fn cursor_neighborhood() {
+		let mut collection = self::test_collection();
+
+		assert_eq!(
+			collection.cursor_neighborhood(),
+			(None, Some(&0), Some(&1)),
+			"should have no previous item at the start"
+		);
+
+		collection.pos = 6;
+		assert_eq!(
+			collection.cursor_neighborhood(),
+			(Some(&5), Some(&9), Some(&8))
+		);
+
+		collection.pos = 9;
+		assert_eq!(
+			collection.cursor_neighborhood(),
+			(Some(&7), Some(&6), None),
+			"should have no next item at the last item"
+		);
+	}
+
+	#[test]
+	fn split_at_cursor() {
+		use alloc::collections::VecDeque;
+
+		let mut collection = self::test_collection();
+		collection.pos = 4;
+
+		let (before, after) = collection.split_at_cursor();
+		assert_eq!(before.get_ref(), &Vec::from([0, 1, 2, 3]));
+		assert_eq!(after.get_ref(), &Vec::from([4, 5, 9, 8, 7, 6]));
+		assert_eq!(before.pos, 0, "should start the first cursor at `0`");
+		assert_eq!(after.pos, 0, "should start the second cursor at `0`");
+
+		let (before, after) = CollectionCursor::new(VecDeque::from([1, 2])).split_at_cursor();
+		assert!(before.get_ref().is_empty());
+		assert_eq!(after.get_ref(), &VecDeque::from([1, 2]));
+	}
+
+	#[test]
+	fn read_array_from_cursor() {
+		let mut collection = self::test_collection();
+		collection.pos = 6;
+
+		assert_eq!(
+			collection.read_array_from_cursor::<4>(),
+			Some([9, 8, 7, 6]),
+			"should read exactly `N` items"
+		);
+		assert_eq!(
+			collection.read_array_from_cursor::<5>(),
+			None,
+			"should return `None` if fewer than `N` items remain"
+		);
+		assert_eq!(collection.pos, 6, "shouldn't move the cursor");
+	}
+
+	#[test]
+	fn read_array_padded() {
+		let mut collection = self::test_collection();
+		collection.pos = 6;
+
+		assert_eq!(
+			collection.read_array_padded::<4>(),
+			[9, 8, 7, 6],
+			"should read exactly `N` items"
+		);
+		assert_eq!(
+			collection.read_array_padded::<6>(),
+			[9, 8, 7, 6, 0, 0],
+			"should pad with default items if fewer than `N` items remain"
+		);
+	}
+
+	#[test]
+	fn for_each_window_mut() {
+		let mut collection = self::test_collection();
+		collection.pos = 3;
+
+		collection.for_each_window_mut(|[a, b]| {
+			*a = -*a;
+			*b = -*b;
+		});
+		assert_eq!(
+			collection.inner,
+			Vec::from([0, 1, 2, -3, -4, -5, -9, -8, -7, 6]),
+			"should modify every pair from the cursor onwards, skipping the leftover item"
+		);
+		assert_eq!(collection.pos, 3, "shouldn't move the cursor");
+
+		let mut collection = CollectionCursor::new([1, 2, 3, 4]);
+		collection.for_each_window_mut(|window: &mut [i32; 4]| window.reverse());
+		assert_eq!(collection.inner, [4, 3, 2, 1], "should work on arrays");
+	}
+
+	#[test]
+	fn clear() {
+		let mut test_vec = self::test_vec();
+		let mut collection = self::test_collection();
+
+		test_vec.clear();
+		collection.clear();
+
+		assert_eq!(collection.inner, test_vec);
+	}
+
+	#[test]
+	fn reset() {
+		let mut collection = self::test_collection();
+		collection.pos = 4;
+		#[cfg(feature = "alloc")]
+		collection.set_checkpoint("checkpoint");
+
+		#[cfg(feature = "alloc")]
+		collection.push_position();
+
+		collection.reset();
+		assert_eq!(
+			collection,
+			CollectionCursor::new(Vec::new()),
+			"should be equivalent to a new cursor over an empty collection"
+		);
+		#[cfg(feature = "alloc")]
+		{
+			assert!(
+				!collection.goto_checkpoint("checkpoint"),
+				"should forget checkpoints"
+			);
+			assert_eq!(
+				collection.pop_position(),
+				None,
+				"should forget saved positions"
+			);
+		}
+	}
+
+	#[test]
+	fn reset_position() {
+		let mut collection = self::test_collection();
+		collection.pos = 4;
+		#[cfg(feature = "alloc")]
+		collection.set_checkpoint("checkpoint");
+
+		collection.reset_position();
+		assert_eq!(collection.pos, 0, "should move the cursor to `0`");
+		assert_eq!(
+			collection.inner,
+			self::test_vec(),
+			"shouldn't modify the collection"
+		);
+		#[cfg(feature = "alloc")]
+		assert!(
+			collection.goto_checkpoint("checkpoint"),
+			"should keep checkpoints"
+		);
+	}
+
+	#[test]
+	fn get_item_at_cursor_mut() {
+		let mut test_vec = self::test_vec();
+		let mut collection = self::test_collection();
+
+		// We deliberately request one item past the end, to test if that's also the same
+		for i in 0..=(test_vec.len()) {
+			collection.pos = i;
+			let test_vec_get = test_vec.get_mut(i);
+			let collection_get = collection.get_item_at_cursor_mut();
+
+			assert_eq!(
+				test_vec_get, collection_get,
+				"should get the same item from the same index (index = `{i}`)"
+			);
+		}
+	}
+
+	#[test]
+	fn get_item_before_cursor_mut() {
+		let mut collection = self::test_collection();
+		assert_eq!(
+			collection.get_item_before_cursor_mut(),
+			None,
+			"should return `None` at the start"
+		);
+
+		collection.pos = 7;
+		*collection.get_item_before_cursor_mut().unwrap() = 20;
+		assert_eq!(collection.inner[6], 20);
+		assert_eq!(collection.pos, 7, "shouldn't move the cursor");
+	}
+
+	fn __set_item(mut collection: TestCollection, mut test_vec: TestVec) {
+		const AT_POS: usize = 5;
+		const TO_VALUE: i32 = 52345;
+
+		test_vec[AT_POS] = TO_VALUE;
+		collection.pos = AT_POS;
+		collection.set_item_at_cursor(TO_VALUE);
+
+		assert_eq!(
+			collection.inner.get(AT_POS),
+			Some(&TO_VALUE),
+			"should modify the inner collection to have the correct value at the head"
+		);
+		assert_eq!(collection.inner, test_vec, "should set only one value");
+	}
+
+	#[test]
+	fn set_item_at_cursor() {
+		let test_vec = self::test_vec();
+		let collection = self::test_collection();
+
+		self::__set_item(collection, test_vec);
+	}
+
+	fn __insert_item(mut collection: TestCollection, mut test_vec: TestVec) {
+		const AT_POS: usize = 5;
+		const TO_VALUE: i32 = 52345;
+
+		test_vec.insert(AT_POS, TO_VALUE);
+		collection.pos = AT_POS;
+		collection.insert_item_at_cursor(TO_VALUE);
+
+		assert_eq!(
+			collection.inner.get(AT_POS),
+			Some(&TO_VALUE),
+			"should modify the inner collection to have the correct value at the head"
+		);
+		assert_eq!(collection.inner, test_vec, "should insert only one value");
+	}
+
+	#[test]
+	fn clear_tail_to_default() {
+		let mut collection = CollectionCursor::new([1, 2, 3, 4, 5]);
+		collection.pos = 2;
+
+		collection.clear_tail_to_default();
+		assert_eq!(
+			collection.inner,
+			[1, 2, 0, 0, 0],
+			"should only reset items at or after the cursor"
+		);
+		assert_eq!(collection.pos, 2, "shouldn't move the cursor");
+	}
+
+	#[test]
+	fn try_overwrite_from_cursor() {
+		let mut collection = CollectionCursor::new([0, 1, 2, 3, 4]);
+		collection.pos = 1;
+
+		assert_eq!(
+			collection.try_overwrite_from_cursor(&[10, 11]),
+			Ok(2),
+			"should succeed when all items fit"
+		);
+		assert_eq!(collection.inner, [0, 10, 11, 3, 4]);
+		assert_eq!(collection.pos, 1, "shouldn't move the cursor");
+
+		collection.pos = 3;
+		assert_eq!(
+			collection.try_overwrite_from_cursor(&[20, 21, 22]),
+			Err(2),
+			"should report how many items were written when running out of room"
+		);
+		assert_eq!(
+			collection.inner,
+			[0, 10, 11, 20, 21],
+			"should still write the items that fit"
+		);
+	}
+
+	#[test]
+	fn modify_item_at_cursor() {
+		let mut collection = self::test_collection();
+		collection.pos = 6;
+
+		let res = collection.modify_item_at_cursor(|item| {
+			*item += 1;
+			*item
+		});
+		assert_eq!(res, Some(10), "should return the closure's result");
+		assert_eq!(
+			collection.inner[6], 10,
+			"should modify the item at the cursor"
+		);
+
+		collection.pos = collection.inner.len();
+		assert_eq!(
+			collection.modify_item_at_cursor(|_| unreachable!()),
+			None::<()>,
+			"should return `None` without calling the closure if the cursor is out-of-bounds"
+		);
+	}
+
+	#[test]
+	fn reserve() {
+		let mut collection = self::test_collection();
+
+		collection.reserve(100);
+		assert!(
+			IndexableCollectionMut::capacity(collection.get_ref()) >= 110,
+			"should reserve capacity in the collection"
+		);
+		assert_eq!(
+			collection.get_ref().len(),
+			10,
+			"shouldn't change the length"
+		);
+
+		let mut collection = CollectionCursor::new([0, 1, 2, 3]);
+		collection.reserve(100);
+		assert_eq!(
+			IndexableCollectionMut::capacity(collection.get_ref()),
+			4,
+			"fixed collections should report their length as their capacity"
+		);
+	}
+
+	#[test]
+	fn insert_item_at_cursor() {
+		let test_vec = self::test_vec();
+		let collection = self::test_collection();
+
+		self::__insert_item(collection, test_vec);
+	}
+
+	#[test]
+	fn set_or_insert_item_at_cursor() {
+		let test_vec = self::test_vec();
+		let collection = self::test_collection();
+
+		self::__set_item(collection.clone(), test_vec.clone());
+		self::__insert_item(collection.clone(), test_vec.clone());
+	}
+
+	#[test]
+	fn remove_item_at_cursor() {
+		const AT_POS: usize = 5;
+
+		let mut test_vec = self::test_vec();
+		let mut collection = self::test_collection();
+
+		let test_vec_res = test_vec.remove(5);
+		collection.pos = AT_POS;
+		let collection_res = collection.remove_item_at_cursor();
+		assert_eq!(
+			collection_res,
 			Some(test_vec_res),
 			"should return the right value"
 		);
@@ -933,4 +3933,350 @@ mod collection_cursor_tests {
 			"should return `None` if the head was out-of-bounds"
 		);
 	}
+
+	#[test]
+	fn swap_remove_item_at() {
+		let mut collection = self::test_collection();
+		collection.pos = 2;
+
+		assert_eq!(collection.swap_remove_item_at(2), Some(2));
+		assert_eq!(
+			collection.inner,
+			Vec::from([0, 1, 6, 3, 4, 5, 9, 8, 7]),
+			"should move the last item into the removed item's place"
+		);
+		assert_eq!(
+			collection.pos, 2,
+			"should leave the cursor on the moved item when removing at the cursor"
+		);
+
+		assert_eq!(collection.swap_remove_item_at(0), Some(0));
+		assert_eq!(
+			collection.pos, 2,
+			"shouldn't move the cursor when removing before it"
+		);
+
+		collection.pos = 7;
+		assert_eq!(collection.inner[7], 8);
+		assert_eq!(collection.swap_remove_item_at(1), Some(1));
+		assert_eq!(
+			collection.pos, 1,
+			"should follow the last item when the cursor was on it"
+		);
+		assert_eq!(collection.inner[1], 8);
+
+		collection.pos = collection.inner.len();
+		assert_eq!(collection.swap_remove_item_at(0), Some(7));
+		assert_eq!(
+			collection.pos,
+			collection.inner.len(),
+			"should keep the cursor at the end when it was at the end"
+		);
+
+		collection.pos = collection.inner.len() - 1;
+		let last = collection.inner[collection.pos];
+		assert_eq!(collection.swap_remove_item_at(collection.pos), Some(last));
+		assert_eq!(
+			collection.pos,
+			collection.inner.len(),
+			"should leave the cursor at the end when removing the last item at the cursor"
+		);
+
+		let before = collection.inner.clone();
+		assert_eq!(
+			collection.swap_remove_item_at(collection.inner.len()),
+			None,
+			"should return `None` when out-of-bounds"
+		);
+		assert_eq!(collection.inner, before, "shouldn't modify the collection");
+	}
+
+	#[test]
+	fn swap_remove_item_at_cursor() {
+		let mut collection = self::test_collection();
+		collection.pos = 4;
+
+		assert_eq!(collection.swap_remove_item_at_cursor(), Some(4));
+		assert_eq!(collection.get_item_at_cursor(), Some(&6));
+
+		collection.pos = collection.inner.len();
+		assert_eq!(
+			collection.swap_remove_item_at_cursor(),
+			None,
+			"should return `None` if the cursor is at the end"
+		);
+	}
+
+	#[test]
+	fn prepend_slice() {
+		let mut collection = self::test_collection();
+		collection.pos = 4;
+
+		collection.prepend_slice(&[10, 11, 12]);
+		assert_eq!(
+			collection.inner,
+			Vec::from([10, 11, 12, 0, 1, 2, 3, 4, 5, 9, 8, 7, 6]),
+			"should insert the items at the start, in order"
+		);
+		assert_eq!(
+			collection.pos, 7,
+			"should move the cursor forward by the number of items inserted"
+		);
+		assert_eq!(collection.get_item_at_cursor(), Some(&4));
+	}
+
+	#[test]
+	#[should_panic = "the cursor's position should not overflow"]
+	fn prepend_slice_overflow() {
+		let mut collection = self::test_collection();
+		collection.pos = usize::MAX;
+
+		collection.prepend_slice(&[10]);
+	}
+
+	#[test]
+	fn append_from() {
+		use alloc::collections::VecDeque;
+
+		let mut collection = self::test_collection();
+		collection.pos = 4;
+
+		collection.append_from([10, 11, 12]);
+		assert_eq!(
+			collection.inner,
+			Vec::from([0, 1, 2, 3, 10, 11, 12, 4, 5, 9, 8, 7, 6]),
+			"should insert the items at the cursor, in order"
+		);
+		assert_eq!(
+			collection.pos, 7,
+			"should move the cursor just past the inserted items"
+		);
+
+		collection.seek_to_end();
+		collection.append_from([20, 21]);
+		assert_eq!(
+			collection.inner,
+			Vec::from([0, 1, 2, 3, 10, 11, 12, 4, 5, 9, 8, 7, 6, 20, 21]),
+			"should extend the collection when at the end"
+		);
+		assert!(collection.is_cursor_at_end());
+
+		let mut collection = CollectionCursor::new(VecDeque::from([0, 1, 2]));
+		collection.pos = 1;
+		collection.append_from([10, 11]);
+		assert_eq!(
+			collection.get_ref(),
+			&VecDeque::from([0, 10, 11, 1, 2]),
+			"should insert the items in order for collections without a bulk insert"
+		);
+		assert_eq!(collection.pos, 3);
+	}
+
+	#[test]
+	fn extend() {
+		let mut collection = self::test_collection();
+		collection.pos = 2;
+
+		collection.extend([10, 11]);
+		collection.extend(core::iter::once(12));
+		assert_eq!(
+			collection.inner,
+			Vec::from([0, 1, 10, 11, 12, 2, 3, 4, 5, 9, 8, 7, 6]),
+			"should insert each item where the cursor is"
+		);
+		assert_eq!(collection.pos, 5, "should move the cursor past each item");
+	}
+
+	#[test]
+	fn from_iter() {
+		let collection: TestCollection = self::test_vec().into_iter().collect();
+		assert_eq!(collection, self::test_collection());
+
+		let collection: CollectionCursor<Vec<_>> = (0..0).collect();
+		assert!(collection.get_ref().is_empty());
+		assert_eq!(collection.pos, 0);
+	}
+
+	#[test]
+	fn remove_item_at_cursor_if() {
+		let mut collection = self::test_collection();
+		collection.pos = 6;
+
+		assert_eq!(
+			collection.remove_item_at_cursor_if(|item| *item == 9),
+			Some(9),
+			"should remove a matching item"
+		);
+		assert_eq!(collection.inner, Vec::from([0, 1, 2, 3, 4, 5, 8, 7, 6]));
+
+		assert_eq!(
+			collection.remove_item_at_cursor_if(|item| *item == 9),
+			None,
+			"shouldn't remove a non-matching item"
+		);
+		assert_eq!(
+			collection.inner,
+			Vec::from([0, 1, 2, 3, 4, 5, 8, 7, 6]),
+			"shouldn't modify the collection"
+		);
+		assert_eq!(collection.pos, 6, "shouldn't move the cursor");
+	}
+
+	#[test]
+	fn set_overwrites_but_insert_grows() {
+		let mut collection = self::test_collection();
+		collection.pos = 3;
+
+		collection.set_item_at_cursor(10);
+		assert_eq!(
+			collection.inner.len(),
+			10,
+			"setting should overwrite the item at the cursor"
+		);
+		assert_eq!(collection.get_item_at_cursor(), Some(&10));
+
+		collection.insert_item_at_cursor(11);
+		assert_eq!(
+			collection.inner.len(),
+			11,
+			"inserting should add an item at the cursor"
+		);
+		assert_eq!(collection.inner[3..5], [11, 10]);
+	}
+
+	#[test]
+	fn array_cursors() {
+		let mut collection = CollectionCursor::new([0, 1, 2, 3, 4]);
+
+		assert_eq!(collection.seek(SeekFrom::End(-2)), Ok(3));
+		assert_eq!(collection.get_item_at_cursor(), Some(&3));
+
+		collection.set_item_at_cursor(10);
+		assert_eq!(
+			collection.into_inner(),
+			[0, 1, 2, 10, 4],
+			"setting should overwrite the item at the cursor"
+		);
+	}
+
+	#[test]
+	fn slice_cursors() {
+		let mut buf = [0, 1, 2, 3, 4];
+
+		let mut collection = CollectionCursor::new(&mut buf[1..]);
+		collection.seek(SeekFrom::Start(2)).unwrap();
+		collection.set_item_at_cursor(10);
+		assert_eq!(
+			buf,
+			[0, 1, 2, 10, 4],
+			"should write through to the borrowed slice"
+		);
+
+		let collection = CollectionCursor::new(&buf[..]);
+		assert_eq!(collection.remaining_slice(), &[0, 1, 2, 10, 4]);
+	}
+
+	#[cfg(feature = "heapless")]
+	#[test]
+	fn heapless_vec_cursors() {
+		let mut collection = CollectionCursor::new(heapless::Vec::<i32, 4>::new());
+
+		collection.insert_item_at_cursor(1);
+		collection.insert_item_at_cursor(0);
+		collection.seek(SeekFrom::End(0)).unwrap();
+		collection.insert_item_at_cursor(2);
+		assert_eq!(collection.get_ref().as_slice(), &[0, 1, 2]);
+
+		assert_eq!(collection.remove_item_at_cursor(), Some(2));
+		assert_eq!(
+			collection.remove_item_at_cursor(),
+			None,
+			"shouldn't remove past the end"
+		);
+	}
+
+	#[test]
+	fn foreign_collection_capacities() {
+		#[cfg(feature = "arrayvec")]
+		{
+			let collection = arrayvec::ArrayVec::<u8, 4>::from_iter([0, 1]);
+			assert_eq!(IndexableCollectionMut::capacity(&collection), 4);
+		}
+
+		#[cfg(feature = "heapless")]
+		{
+			let collection = heapless::Vec::<u8, 4>::from_iter([0, 1]);
+			assert_eq!(IndexableCollectionMut::capacity(&collection), 4);
+		}
+
+		#[cfg(feature = "smallvec")]
+		{
+			let mut collection = smallvec::SmallVec::<[u8; 4]>::from_iter([0, 1]);
+			assert_eq!(IndexableCollectionMut::capacity(&collection), 4);
+			IndexableCollectionMut::reserve(&mut collection, 8);
+			assert!(IndexableCollectionMut::capacity(&collection) >= 10);
+		}
+
+		#[cfg(feature = "tinyvec")]
+		{
+			let collection = tinyvec::ArrayVec::<[u8; 4]>::from_iter([0, 1]);
+			assert_eq!(IndexableCollectionMut::capacity(&collection), 4);
+
+			let mut buf = [0u8; 4];
+			let collection = tinyvec::SliceVec::from_slice_len(&mut buf, 2);
+			assert_eq!(IndexableCollectionMut::capacity(&collection), 4);
+		}
+
+		#[cfg(all(feature = "tinyvec", feature = "alloc"))]
+		{
+			let mut collection = tinyvec::TinyVec::<[u8; 4]>::from_iter([0, 1]);
+			assert_eq!(IndexableCollectionMut::capacity(&collection), 4);
+			IndexableCollectionMut::reserve(&mut collection, 8);
+			assert!(IndexableCollectionMut::capacity(&collection) >= 10);
+		}
+	}
+
+	#[cfg(feature = "arrayvec")]
+	#[test]
+	#[should_panic = "CapacityError: insufficient capacity"]
+	fn arrayvec_insert_past_capacity_panics() {
+		let mut collection = CollectionCursor::new(arrayvec::ArrayVec::<u8, 4>::new());
+
+		for item in 0..4 {
+			collection.insert_item_at_cursor(item);
+			collection.seek_forward_one();
+		}
+		assert_eq!(
+			collection.get_ref().as_slice(),
+			&[0, 1, 2, 3],
+			"should be able to fill the vec to capacity"
+		);
+
+		collection.insert_item_at_cursor(4);
+	}
+
+	#[test]
+	fn get_item_at_cursor_or_insert_default() {
+		let mut collection = self::test_collection();
+		collection.pos = 6;
+
+		let item = collection.get_item_at_cursor_or_insert_default();
+		assert_eq!(*item, 9, "should return the existing item");
+		*item = 52345;
+		assert_eq!(
+			collection.inner,
+			Vec::from([0, 1, 2, 3, 4, 5, 52345, 8, 7, 6]),
+			"should return a reference into the collection"
+		);
+
+		collection.pos = 10;
+		let item = collection.get_item_at_cursor_or_insert_default();
+		assert_eq!(*item, 0, "should insert a default item at the end");
+		assert_eq!(
+			collection.inner.len(),
+			11,
+			"should grow the collection when inserting"
+		);
+		assert_eq!(collection.pos, 10, "shouldn't move the cursor");
+	}
 }
@@ -0,0 +1,204 @@
+use std::io;
+
+use crate::{
+	CollectionCursor,
+	IndexableCollection,
+	IndexableCollectionResizable,
+	SeekFrom,
+	strict_checked,
+};
+
+impl<Tape: IndexableCollection> io::Seek for CollectionCursor<Tape> {
+	/// Moves the cursor to a new index, as with [`CollectionCursor::seek()`]. Positions and
+	/// offsets are measured in items, rather than bytes.
+	///
+	/// # Errors
+	/// Returns an error of kind [`io::ErrorKind::InvalidInput`] if the new position would be
+	/// before `0` or after `self.get_ref().len()`, or if `pos` does not fit within a `usize` or
+	/// `isize`. In these cases, the cursor will not be moved.
+	///
+	/// # Panics
+	/// When the `strict-arithmetic` feature is enabled and debug assertions are on, this panics if
+	/// `pos` does not fit within a `usize` or `isize`, or under the same circumstances as
+	/// [`CollectionCursor::seek()`].
+	fn seek(&mut self, pos: io::SeekFrom) -> io::Result<u64> {
+		let converted = match pos {
+			io::SeekFrom::Start(p) => usize::try_from(p).ok().map(SeekFrom::Start),
+			io::SeekFrom::End(p) => isize::try_from(p).ok().map(SeekFrom::End),
+			io::SeekFrom::Current(p) => isize::try_from(p).ok().map(SeekFrom::Current),
+		};
+		let pos = strict_checked(
+			converted,
+			format_args!("converting `{pos:?}` to a position overflowed `usize` or `isize`"),
+		)
+		.ok_or_else(|| {
+			io::Error::new(
+				io::ErrorKind::InvalidInput,
+				"the seek position does not fit within a `usize` or `isize`",
+			)
+		})?;
+
+		let new_pos = CollectionCursor::seek(self, pos)
+			.map_err(|err| io::Error::new(io::ErrorKind::InvalidInput, err))?;
+		Ok(new_pos as u64)
+	}
+}
+
+impl<Tape: IndexableCollection<Item = u8>> io::Read for CollectionCursor<Tape> {
+	/// Copies bytes from the cursor onward into `buf`, and moves the cursor past them. Returns the
+	/// number of bytes copied, which is `0` once the cursor reaches the end of the collection.
+	///
+	/// # Errors
+	/// This never returns an error.
+	fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+		let mut read = 0;
+		for (dst, src) in buf.iter_mut().zip(self.iter_from_cursor()) {
+			*dst = *src;
+			read += 1;
+		}
+
+		self.pos += read;
+		Ok(read)
+	}
+}
+
+impl<Tape: IndexableCollectionResizable<Item = u8>> io::Write for CollectionCursor<Tape> {
+	/// Writes all of `buf` at the cursor, and moves the cursor past the written bytes. Returns
+	/// `buf.len()`.
+	///
+	/// Like writing to a file, bytes at the cursor are overwritten. Once the end of the collection
+	/// is reached, the remaining bytes are appended, growing the collection.
+	///
+	/// # Errors
+	/// This never returns an error.
+	///
+	/// # Panics
+	/// Panics if the set or insert operation panics, such as when the collection can't grow any
+	/// further. The circumstances for a panic are defined by the inner collection.
+	fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+		self.clamp_to_end();
+		for &byte in buf {
+			self.set_or_insert_item_at_cursor(byte);
+			self.pos += 1;
+		}
+
+		Ok(buf.len())
+	}
+
+	/// Does nothing, as bytes are written directly to the collection.
+	///
+	/// # Errors
+	/// This never returns an error.
+	fn flush(&mut self) -> io::Result<()> {
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod std_io_tests {
+	extern crate alloc;
+
+	use alloc::vec::Vec;
+	use std::io::{self, Read, Seek, Write};
+
+	use crate::{CollectionCursor, collection_cursor_tests::test_collection};
+
+	#[test]
+	fn seek() {
+		let mut collection = self::test_collection();
+
+		assert_eq!(
+			Seek::seek(&mut collection, io::SeekFrom::Start(4)).unwrap(),
+			4
+		);
+		assert_eq!(
+			Seek::seek(&mut collection, io::SeekFrom::Current(-1)).unwrap(),
+			3
+		);
+		assert_eq!(
+			Seek::seek(&mut collection, io::SeekFrom::End(-2)).unwrap(),
+			8
+		);
+		assert_eq!(collection.stream_position().unwrap(), 8);
+
+		collection.rewind().unwrap();
+		assert_eq!(collection.position(), 0);
+	}
+
+	#[test]
+	fn seek_errors() {
+		let mut collection = self::test_collection();
+		collection.seek_to_last_item();
+
+		for pos in [
+			io::SeekFrom::Start(11),
+			io::SeekFrom::End(1),
+			io::SeekFrom::Current(-10),
+			io::SeekFrom::Start(u64::MAX),
+		] {
+			let err = Seek::seek(&mut collection, pos).unwrap_err();
+			assert_eq!(err.kind(), io::ErrorKind::InvalidInput, "{pos:?}");
+			assert_eq!(collection.position(), 9, "shouldn't move after failing");
+		}
+	}
+
+	#[test]
+	#[cfg(all(feature = "strict-arithmetic", debug_assertions))]
+	#[should_panic = "overflowed `usize`"]
+	fn strict_arithmetic_panics_on_overflow() {
+		let mut collection = self::test_collection();
+		collection.pos = usize::MAX;
+		let _ = Seek::seek(&mut collection, io::SeekFrom::Current(1));
+	}
+
+	#[test]
+	#[cfg(not(all(feature = "strict-arithmetic", debug_assertions)))]
+	fn overflow_returns_error() {
+		let mut collection = self::test_collection();
+		collection.pos = usize::MAX;
+
+		let err = Seek::seek(&mut collection, io::SeekFrom::Current(1)).unwrap_err();
+		assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+		assert_eq!(collection.pos, usize::MAX, "shouldn't move the cursor");
+	}
+
+	#[test]
+	fn read() {
+		let mut collection = CollectionCursor::new(Vec::from(*b"hello world"));
+		let mut buf = [0; 5];
+
+		assert_eq!(collection.read(&mut buf).unwrap(), 5);
+		assert_eq!(&buf, b"hello");
+		assert_eq!(collection.position(), 5, "should move past the bytes read");
+
+		let mut rest = Vec::new();
+		assert_eq!(collection.read_to_end(&mut rest).unwrap(), 6);
+		assert_eq!(rest, b" world");
+
+		assert_eq!(collection.read(&mut buf).unwrap(), 0, "should be at EOF");
+		assert_eq!(collection.position(), 11);
+	}
+
+	#[test]
+	fn write() {
+		let mut collection = CollectionCursor::new(Vec::new());
+
+		collection.write_all(b"hello").unwrap();
+		assert_eq!(collection.get_ref(), b"hello");
+		assert_eq!(
+			collection.position(),
+			5,
+			"should move past the bytes written"
+		);
+
+		collection.seek_to_start();
+		collection.seek_forward_one();
+		collection.write_all(b"ELLO WORLD").unwrap();
+		assert_eq!(
+			collection.get_ref(),
+			b"hELLO WORLD",
+			"should overwrite existing bytes, then grow the collection"
+		);
+		assert_eq!(collection.position(), 11);
+	}
+}
@@ -2,30 +2,71 @@ extern crate alloc;
 
 use alloc::{collections::VecDeque, vec::Vec};
 
-use crate::{IndexableCollection, IndexableCollectionMut, IndexableCollectionResizable};
+use crate::{
+	ContiguousCollection,
+	ContiguousCollectionMut,
+	IndexableCollection,
+	IndexableCollectionMut,
+	IndexableCollectionResizable,
+	SplittableCollection,
+};
 
 impl<T> IndexableCollection for Vec<T> {
 	type Item = T;
 	forward_indexable!();
+	forward_indexable!(as_contiguous_slice);
 }
 
 impl<T> IndexableCollectionMut for Vec<T> {
 	forward_mutable!();
+	forward_mutable!(reserve);
 }
 
 impl<T> IndexableCollectionResizable for Vec<T> {
 	forward_resizable!(check_len_on_remove = true);
+
+	fn insert_items<I: IntoIterator<Item = Self::Item>>(&mut self, index: usize, iter: I) -> usize {
+		let len_before = self.len();
+		self.splice(index..index, iter);
+		self.len() - len_before
+	}
+}
+
+impl<T> SplittableCollection for Vec<T> {
+	fn split_off(&mut self, at: usize) -> Self {
+		self.split_off(at)
+	}
+}
+
+impl<T> ContiguousCollection for Vec<T> {
+	forward_contiguous!();
+}
+
+impl<T> ContiguousCollectionMut for Vec<T> {
+	forward_contiguous_mut!();
 }
 
 impl<T> IndexableCollection for VecDeque<T> {
 	type Item = T;
 	forward_indexable!();
+
+	fn as_contiguous_slice(&self) -> Option<&[Self::Item]> {
+		let (front, back) = self.as_slices();
+		back.is_empty().then_some(front)
+	}
 }
 
 impl<T> IndexableCollectionMut for VecDeque<T> {
 	forward_mutable!();
+	forward_mutable!(reserve);
 }
 
 impl<T> IndexableCollectionResizable for VecDeque<T> {
 	forward_resizable!(check_len_on_remove = false);
 }
+
+impl<T> SplittableCollection for VecDeque<T> {
+	fn split_off(&mut self, at: usize) -> Self {
+		self.split_off(at)
+	}
+}
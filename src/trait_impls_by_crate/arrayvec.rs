@@ -1,16 +1,34 @@
 use arrayvec::ArrayVec;
 
-use crate::{IndexableCollection, IndexableCollectionMut, IndexableCollectionResizable};
+use crate::{
+	ContiguousCollection,
+	ContiguousCollectionMut,
+	IndexableCollection,
+	IndexableCollectionMut,
+	IndexableCollectionResizable,
+};
 
 impl<T, const CAP: usize> IndexableCollection for ArrayVec<T, CAP> {
 	type Item = T;
 	forward_indexable!();
+	forward_indexable!(as_contiguous_slice);
 }
 
 impl<T, const CAP: usize> IndexableCollectionMut for ArrayVec<T, CAP> {
 	forward_mutable!();
+	forward_mutable!(capacity);
 }
 
+// `ArrayVec::insert()` panics if the vec is already full, and so `insert_item()` (along with any
+// cursor methods that insert) will panic in that case too.
 impl<T, const CAP: usize> IndexableCollectionResizable for ArrayVec<T, CAP> {
 	forward_resizable!(check_len_on_remove = true);
 }
+
+impl<T, const CAP: usize> ContiguousCollection for ArrayVec<T, CAP> {
+	forward_contiguous!();
+}
+
+impl<T, const CAP: usize> ContiguousCollectionMut for ArrayVec<T, CAP> {
+	forward_contiguous_mut!();
+}
@@ -1,9 +1,19 @@
-use crate::{IndexableCollection, IndexableCollectionMut};
+use crate::{
+	ContiguousCollection,
+	ContiguousCollectionMut,
+	IndexableCollection,
+	IndexableCollectionMut,
+	IndexableCollectionResizable,
+};
 
+// Arrays can't grow or shrink, so they only implement `IndexableCollectionMut`, and not
+// `IndexableCollectionResizable`. Use a collection such as `arrayvec::ArrayVec` for a
+// fixed-capacity collection that can be resized.
 impl<T, const N: usize> IndexableCollection for [T; N] {
 	type Item = T;
 
 	forward_indexable!(get_item);
+	forward_indexable!(as_contiguous_slice);
 
 	fn len(&self) -> usize {
 		N
@@ -13,3 +23,120 @@ impl<T, const N: usize> IndexableCollection for [T; N] {
 impl<T, const N: usize> IndexableCollectionMut for [T; N] {
 	forward_mutable!();
 }
+
+impl<T, const N: usize> ContiguousCollection for [T; N] {
+	forward_contiguous!();
+}
+
+impl<T, const N: usize> ContiguousCollectionMut for [T; N] {
+	forward_contiguous_mut!();
+}
+
+// As with arrays, slices can't grow or shrink, so they don't implement
+// `IndexableCollectionResizable`. A cursor can use a slice through a reference, such as `&[T]` or
+// `&mut [T]`.
+impl<T> IndexableCollection for [T] {
+	type Item = T;
+	forward_indexable!();
+	forward_indexable!(as_contiguous_slice);
+}
+
+impl<T> IndexableCollectionMut for [T] {
+	forward_mutable!();
+}
+
+impl<T> ContiguousCollection for [T] {
+	forward_contiguous!();
+}
+
+impl<T> ContiguousCollectionMut for [T] {
+	forward_contiguous_mut!();
+}
+
+// References to collections forward to the collection they reference, so that a cursor can borrow
+// a collection rather than owning it.
+impl<C: IndexableCollection + ?Sized> IndexableCollection for &C {
+	type Item = C::Item;
+
+	fn len(&self) -> usize {
+		(**self).len()
+	}
+
+	fn get_item(&self, index: usize) -> Option<&Self::Item> {
+		(**self).get_item(index)
+	}
+
+	fn as_contiguous_slice(&self) -> Option<&[Self::Item]> {
+		(**self).as_contiguous_slice()
+	}
+}
+
+impl<C: ContiguousCollection + ?Sized> ContiguousCollection for &C {
+	fn as_slice(&self) -> &[Self::Item] {
+		(**self).as_slice()
+	}
+}
+
+impl<C: IndexableCollection + ?Sized> IndexableCollection for &mut C {
+	type Item = C::Item;
+
+	fn len(&self) -> usize {
+		(**self).len()
+	}
+
+	fn get_item(&self, index: usize) -> Option<&Self::Item> {
+		(**self).get_item(index)
+	}
+
+	fn as_contiguous_slice(&self) -> Option<&[Self::Item]> {
+		(**self).as_contiguous_slice()
+	}
+}
+
+impl<C: IndexableCollectionMut + ?Sized> IndexableCollectionMut for &mut C {
+	fn get_item_mut(&mut self, index: usize) -> Option<&mut Self::Item> {
+		(**self).get_item_mut(index)
+	}
+
+	fn set_item(&mut self, index: usize, element: Self::Item) {
+		(**self).set_item(index, element);
+	}
+
+	fn capacity(&self) -> usize {
+		(**self).capacity()
+	}
+
+	fn reserve(&mut self, additional: usize) {
+		(**self).reserve(additional);
+	}
+}
+
+impl<C: IndexableCollectionResizable + ?Sized> IndexableCollectionResizable for &mut C {
+	fn insert_item(&mut self, index: usize, element: Self::Item) {
+		(**self).insert_item(index, element);
+	}
+
+	fn remove_item(&mut self, index: usize) -> Option<Self::Item> {
+		(**self).remove_item(index)
+	}
+
+	fn swap_remove_item(&mut self, index: usize) -> Option<Self::Item> {
+		(**self).swap_remove_item(index)
+	}
+
+	fn clear(&mut self) {
+		(**self).clear();
+	}
+}
+
+impl<C: ContiguousCollection + ?Sized> ContiguousCollection for &mut C {
+	fn as_slice(&self) -> &[Self::Item] {
+		(**self).as_slice()
+	}
+}
+
+impl<C: ContiguousCollectionMut + ?Sized> ContiguousCollectionMut for &mut C {
+	fn as_mut_slice(&mut self) -> &mut [Self::Item] {
+		(**self).as_mut_slice()
+	}
+}
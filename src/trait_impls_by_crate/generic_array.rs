@@ -1,11 +1,17 @@
 use generic_array::{ArrayLength, GenericArray};
 
-use crate::{IndexableCollection, IndexableCollectionMut};
+use crate::{
+	ContiguousCollection,
+	ContiguousCollectionMut,
+	IndexableCollection,
+	IndexableCollectionMut,
+};
 
 impl<T, N: ArrayLength> IndexableCollection for GenericArray<T, N> {
 	type Item = T;
 
 	forward_indexable!(get_item);
+	forward_indexable!(as_contiguous_slice);
 
 	fn len(&self) -> usize {
 		N::USIZE
@@ -15,3 +21,11 @@ impl<T, N: ArrayLength> IndexableCollection for GenericArray<T, N> {
 impl<T, N: ArrayLength> IndexableCollectionMut for GenericArray<T, N> {
 	forward_mutable!();
 }
+
+impl<T, N: ArrayLength> ContiguousCollection for GenericArray<T, N> {
+	forward_contiguous!();
+}
+
+impl<T, N: ArrayLength> ContiguousCollectionMut for GenericArray<T, N> {
+	forward_contiguous_mut!();
+}
@@ -0,0 +1,59 @@
+use heapless::Vec;
+
+use crate::{
+	ContiguousCollection,
+	ContiguousCollectionMut,
+	IndexableCollection,
+	IndexableCollectionMut,
+	IndexableCollectionResizable,
+};
+
+impl<T, const N: usize> IndexableCollection for Vec<T, N> {
+	type Item = T;
+	forward_indexable!(get_item);
+	forward_indexable!(as_contiguous_slice);
+
+	// `heapless::Vec` only gets `len()` through its `Deref` impl, so calling it directly would
+	// call this method instead.
+	fn len(&self) -> usize {
+		self.as_slice().len()
+	}
+}
+
+impl<T, const N: usize> IndexableCollectionMut for Vec<T, N> {
+	forward_mutable!();
+
+	// As with `len()`, `heapless::Vec` only gets `capacity()` through its `Deref` impl. Its
+	// capacity is always `N`, though.
+	fn capacity(&self) -> usize {
+		N
+	}
+}
+
+impl<T, const N: usize> IndexableCollectionResizable for Vec<T, N> {
+	// `heapless::Vec::insert()` returns the element rather than panicking when the vec is full.
+	// However, we have no way to return it, so we panic instead - the same as `arrayvec::ArrayVec`
+	// does.
+	fn insert_item(&mut self, index: usize, element: Self::Item) {
+		if self.insert(index, element).is_err() {
+			panic!("tried to insert into a full `heapless::Vec` (capacity is {N})");
+		}
+	}
+
+	// This shifts every item after `index` back by one, and so is `O(n)`.
+	fn remove_item(&mut self, index: usize) -> Option<Self::Item> {
+		(index < self.len()).then(|| self.remove(index))
+	}
+
+	fn clear(&mut self) {
+		self.clear();
+	}
+}
+
+impl<T, const N: usize> ContiguousCollection for Vec<T, N> {
+	forward_contiguous!();
+}
+
+impl<T, const N: usize> ContiguousCollectionMut for Vec<T, N> {
+	forward_contiguous_mut!();
+}
@@ -13,6 +13,11 @@ macro_rules! forward_indexable {
 			self.get(index)
 		}
 	};
+	(as_contiguous_slice) => {
+		fn as_contiguous_slice(&self) -> Option<&[Self::Item]> {
+			Some(self)
+		}
+	};
 }
 
 macro_rules! forward_mutable {
@@ -25,6 +30,42 @@ macro_rules! forward_mutable {
 			self[index] = element;
 		}
 	};
+	(capacity) => {
+		fn capacity(&self) -> usize {
+			self.capacity()
+		}
+	};
+	(reserve) => {
+		forward_mutable!(capacity);
+
+		fn reserve(&mut self, additional: usize) {
+			self.reserve(additional);
+		}
+	};
+}
+
+#[allow(
+	unused_macros,
+	reason = "This is only used by the per-crate modules, all of which may be disabled by features."
+)]
+macro_rules! forward_contiguous {
+	() => {
+		fn as_slice(&self) -> &[Self::Item] {
+			self
+		}
+	};
+}
+
+#[allow(
+	unused_macros,
+	reason = "This is only used by the per-crate modules, all of which may be disabled by features."
+)]
+macro_rules! forward_contiguous_mut {
+	() => {
+		fn as_mut_slice(&mut self) -> &mut [Self::Item] {
+			self
+		}
+	};
 }
 
 macro_rules! forward_resizable {
@@ -359,6 +400,9 @@ mod arrayvec;
 #[cfg(feature = "generic-array")]
 mod generic_array;
 
+#[cfg(feature = "heapless")]
+mod heapless;
+
 #[cfg(feature = "smallvec")]
 mod smallvec;
 
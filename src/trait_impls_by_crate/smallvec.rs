@@ -1,16 +1,32 @@
 use smallvec::{Array, SmallVec};
 
-use crate::{IndexableCollection, IndexableCollectionMut, IndexableCollectionResizable};
+use crate::{
+	ContiguousCollection,
+	ContiguousCollectionMut,
+	IndexableCollection,
+	IndexableCollectionMut,
+	IndexableCollectionResizable,
+};
 
 impl<A: Array> IndexableCollection for SmallVec<A> {
 	type Item = <A as Array>::Item;
 	forward_indexable!();
+	forward_indexable!(as_contiguous_slice);
 }
 
 impl<A: Array> IndexableCollectionMut for SmallVec<A> {
 	forward_mutable!();
+	forward_mutable!(reserve);
 }
 
 impl<A: Array> IndexableCollectionResizable for SmallVec<A> {
 	forward_resizable!(check_len_on_remove = true);
 }
+
+impl<A: Array> ContiguousCollection for SmallVec<A> {
+	forward_contiguous!();
+}
+
+impl<A: Array> ContiguousCollectionMut for SmallVec<A> {
+	forward_contiguous_mut!();
+}
@@ -2,46 +2,84 @@
 use tinyvec::TinyVec;
 use tinyvec::{Array, ArrayVec, SliceVec};
 
-use crate::{IndexableCollection, IndexableCollectionMut, IndexableCollectionResizable};
+use crate::{
+	ContiguousCollection,
+	ContiguousCollectionMut,
+	IndexableCollection,
+	IndexableCollectionMut,
+	IndexableCollectionResizable,
+};
 
 impl<A: Array> IndexableCollection for ArrayVec<A> {
 	type Item = <A as Array>::Item;
 	forward_indexable!();
+	forward_indexable!(as_contiguous_slice);
 }
 
 impl<A: Array> IndexableCollectionMut for ArrayVec<A> {
 	forward_mutable!();
+	forward_mutable!(capacity);
 }
 
 impl<A: Array> IndexableCollectionResizable for ArrayVec<A> {
 	forward_resizable!(check_len_on_remove = true);
 }
 
+impl<A: Array> ContiguousCollection for ArrayVec<A> {
+	forward_contiguous!();
+}
+
+impl<A: Array> ContiguousCollectionMut for ArrayVec<A> {
+	forward_contiguous_mut!();
+}
+
 impl<'s, T> IndexableCollection for SliceVec<'s, T> {
 	type Item = T;
 	forward_indexable!();
+	forward_indexable!(as_contiguous_slice);
 }
 
 impl<'s, T> IndexableCollectionMut for SliceVec<'s, T> {
 	forward_mutable!();
+	forward_mutable!(capacity);
 }
 
 impl<'s, T: Default> IndexableCollectionResizable for SliceVec<'s, T> {
 	forward_resizable!(check_len_on_remove = true);
 }
 
+impl<'s, T> ContiguousCollection for SliceVec<'s, T> {
+	forward_contiguous!();
+}
+
+impl<'s, T> ContiguousCollectionMut for SliceVec<'s, T> {
+	forward_contiguous_mut!();
+}
+
 #[cfg(feature = "alloc")]
 impl<A: Array> IndexableCollection for TinyVec<A> {
 	type Item = <A as Array>::Item;
 	forward_indexable!();
+	forward_indexable!(as_contiguous_slice);
 }
 
 #[cfg(feature = "alloc")]
 impl<A: Array> IndexableCollectionMut for TinyVec<A> {
 	forward_mutable!();
+	forward_mutable!(reserve);
 }
 
 #[cfg(feature = "alloc")]
 impl<A: Array> IndexableCollectionResizable for TinyVec<A> {
 	forward_resizable!(check_len_on_remove = true);
 }
+
+#[cfg(feature = "alloc")]
+impl<A: Array> ContiguousCollection for TinyVec<A> {
+	forward_contiguous!();
+}
+
+#[cfg(feature = "alloc")]
+impl<A: Array> ContiguousCollectionMut for TinyVec<A> {
+	forward_contiguous_mut!();
+}